@@ -0,0 +1,24 @@
+use factoring::poly::Polynomial;
+use std::hint::black_box;
+use std::time::Instant;
+
+// How many times each polynomial is parsed and solved
+const ITERATIONS: u32 = 200_000;
+
+// Times parsing and solving a polynomial, printing the average time per iteration
+fn bench(polynomial: &str) {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        let parsed: Polynomial = black_box(polynomial).parse().unwrap();
+        black_box(parsed.roots());
+    }
+    let nanos = start.elapsed().as_nanos() / u128::from(ITERATIONS);
+    println!("{:<32} {:>8} ns/iter", polynomial, nanos);
+}
+
+fn main() {
+    bench("x^2 + 4x + 4");
+    bench("-3.5x^2 + 1,000x - 0.25");
+    bench("x^2 = 4x - 4");
+    bench("x^4 - 10x^3 + 35x^2 - 50x + 24");
+}
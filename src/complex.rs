@@ -0,0 +1,97 @@
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+// A complex number, used for roots that aren't real
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    // Creates a complex number from its real and imaginary parts
+    pub fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    // Creates a complex number with no imaginary part
+    pub fn real(re: f64) -> Self {
+        Self::new(re, 0_f64)
+    }
+
+    // Checks if the number has no imaginary part
+    pub fn is_real(&self) -> bool {
+        self.im == 0_f64
+    }
+
+    // Gets the complex conjugate
+    pub fn conjugate(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    // Gets the squared magnitude, which avoids a square root
+    pub fn norm_squared(self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, other: Complex) -> Complex {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, other: Complex) -> Complex {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, other: Complex) -> Complex {
+        Complex::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+impl Div for Complex {
+    type Output = Complex;
+
+    fn div(self, other: Complex) -> Complex {
+        let numerator = self * other.conjugate();
+        let denominator = other.norm_squared();
+        Complex::new(numerator.re / denominator, numerator.im / denominator)
+    }
+}
+
+impl Neg for Complex {
+    type Output = Complex;
+
+    fn neg(self) -> Complex {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Tests complex arithmetic
+    #[test]
+    fn complex_arithmetic() {
+        let (z, w) = (Complex::new(1_f64, 2_f64), Complex::new(3_f64, -1_f64));
+        assert_eq!(z + w, Complex::new(4_f64, 1_f64));
+        assert_eq!(z - w, Complex::new(-2_f64, 3_f64));
+        assert_eq!(z * w, Complex::new(5_f64, 5_f64));
+        assert_eq!((z * w) / w, z);
+        assert_eq!(-z, Complex::new(-1_f64, -2_f64));
+        assert!(Complex::real(2_f64).is_real() && !z.is_real());
+    }
+}
@@ -0,0 +1,74 @@
+// A bank of problems to solve, loaded from a TOML config like:
+//
+// [[problem]]
+// name = "q1"
+// expr = "x^2 - 1"
+use crate::poly::{ParseError, ParseOptions};
+use crate::solve::{solve_with, Solution};
+use serde::Deserialize;
+
+// A named polynomial to solve, along with the options to parse it with
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Problem {
+    pub name: String,
+    pub expr: String,
+    #[serde(default)]
+    pub implicit_exponent: bool,
+    #[serde(default)]
+    pub decimal_comma: bool,
+}
+
+impl Problem {
+    // Gets the options the problem's polynomial is parsed with
+    pub fn options(&self) -> ParseOptions {
+        ParseOptions {
+            implicit_exponent: self.implicit_exponent,
+            decimal_comma: self.decimal_comma,
+        }
+    }
+
+    // Parses the problem's polynomial and finds its roots
+    pub fn solve(&self) -> Result<Solution, ParseError> {
+        solve_with(&self.expr, &self.options())
+    }
+}
+
+// The layout of a config file
+#[derive(Deserialize)]
+struct Config {
+    #[serde(default)]
+    problem: Vec<Problem>,
+}
+
+// Loads the problems listed in a TOML config, in the order they are written
+pub fn load_problems(config: &str) -> Result<Vec<Problem>, toml::de::Error> {
+    toml::from_str::<Config>(config).map(|config| config.problem)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Tests loading and solving the problems in a config
+    #[test]
+    fn solve_config() {
+        let problems = load_problems(
+            r#"
+            [[problem]]
+            name = "q1"
+            expr = "x^2 - 1"
+
+            [[problem]]
+            name = "q2"
+            expr = "x2 - 5x + 6"
+            implicit_exponent = true
+            "#,
+        )
+        .unwrap();
+        assert_eq!(problems.len(), 2);
+        assert_eq!(problems[0].name, "q1");
+        assert_eq!(problems[0].solve().unwrap().roots, (1_f64, -1_f64));
+        assert_eq!(problems[1].solve().unwrap().roots, (3_f64, 2_f64));
+        assert!(load_problems("[[problem]]\nname = \"q1\"").is_err());
+    }
+}
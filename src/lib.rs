@@ -0,0 +1,41 @@
+#![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
+
+extern crate alloc;
+
+// Without std, the float functions that core doesn't provide come from libm
+#[cfg(all(not(feature = "std"), not(feature = "libm")))]
+compile_error!("the libm feature is needed when the std feature is disabled");
+
+mod complex;
+#[cfg(feature = "config")]
+pub mod config;
+#[cfg(all(not(feature = "std"), not(test)))]
+mod math;
+pub mod poly;
+#[cfg(feature = "python")]
+mod python;
+mod solve;
+#[doc(hidden)]
+pub mod testing;
+
+pub use complex::Complex;
+#[cfg(feature = "std")]
+pub use solve::solve_binary_stream;
+pub use solve::{parse_warnings, solve, solve_with, Solution, SCHEMA_VERSION};
+
+// Gets the version of the crate, as set in Cargo.toml
+pub fn crate_version() -> &'static str {
+    env!("CARGO_PKG_VERSION")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Tests that the version matches the package version
+    #[test]
+    fn version() {
+        assert_eq!(crate_version(), env!("CARGO_PKG_VERSION"));
+        assert!(crate_version().starts_with("1."));
+    }
+}
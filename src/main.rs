@@ -1,218 +1,1368 @@
-pub mod poly {
+use clap::{App, AppSettings, Arg, SubCommand};
+#[cfg(feature = "exact")]
+use factoring::poly::exact::ExactPolynomial;
+use factoring::poly::finder::{Cardano, CompanionMatrix, Newton, QuadraticFormula, RootFinder};
+use factoring::poly::{
+    format_root, function_prefix_length, strip_trailing_punctuation, ParseError, ParseOptions,
+    Polynomial, Rational, RootKind,
+};
+use factoring::Solution;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::process;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
 
-    // Represents a polynomial of the form Ax^2 + Bx + C
-    pub struct Polynomial {
-        a: f64,
-        b: f64,
-        c: f64,
+// The longest polynomial string allowed unless --max-length is given, and the most it can be raised
+// to, so that huge inputs are still rejected
+const DEFAULT_MAX_LENGTH: usize = 100;
+const MAX_LENGTH_CEILING: usize = 10_000;
+
+// The most rows a table of values can have, so that a tiny step can't print billions of them
+const MAX_TABLE_ROWS: usize = 10_000;
+
+// Finds every problem with a polynomial string: non-ASCII input besides √, being longer than the
+// max length, and each unsupported character along with its position. Any extra characters given
+// are also allowed, as are * and / inside parentheses, for arithmetic coefficients like (3*2). A
+// leading "f(x) =" naming the polynomial and trailing punctuation aren't checked.
+fn validate_polynomial(s: &str, extra_chars: &[char], max_length: usize) -> Vec<String> {
+    let mut errors = Vec::new();
+    if !s.chars().all(|c| c.is_ascii() || c == '√') {
+        errors.push("Polynomial not ASCII.".to_owned());
+    }
+    if s.len() > max_length {
+        errors.push("Polynomial too long.".to_owned());
     }
-    impl Polynomial {
-        // Gets the roots of the polynomial through the quadratic equation
-        pub fn roots(&self) -> (f64, f64) {
-            let root1 =
-                (-self.b + (self.b * self.b - 4_f64 * self.a * self.c).sqrt()) / (2_f64 * self.a);
-            let root2 =
-                (-self.b - (self.b * self.b - 4_f64 * self.a * self.c).sqrt()) / (2_f64 * self.a);
-            (root1, root2)
+    let allowed_chars = [
+        '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '+', '-', '^', '.', ',', 'x', 'X', '=',
+        ' ', '\t', 's', 'q', 'r', 't', '(', ')', '√',
+    ];
+    let start = function_prefix_length(s);
+    let end = strip_trailing_punctuation(s).len();
+    let mut depth = 0_usize;
+    for (i, c) in s[..end].char_indices().skip_while(|(i, _)| *i < start) {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            _ => (),
+        }
+        let arithmetic = depth > 0 && (c == '*' || c == '/');
+        if !allowed_chars.contains(&c) && !extra_chars.contains(&c) && !arithmetic {
+            errors.push(format!(
+                "Polynomial has unsupported character '{}' at position {}.",
+                c, i
+            ));
         }
     }
-    // Represents an inner expression of the form Cx^D
-    struct Subpolynomial {
-        coefficent: f64,
-        degree: u8,
+    errors
+}
+
+// Checks if an argument is a flag rather than a polynomial with a leading minus, like a mistyped
+// --factord. Leading hyphens are allowed so polynomials like -x^2 can be passed, which also lets
+// unknown flags through as the polynomial, so they're caught here instead.
+fn looks_like_flag(s: &str) -> bool {
+    let name = s.trim_start_matches('-');
+    s.starts_with('-')
+        && name.len() >= 2
+        && name.chars().all(|c| c.is_ascii_alphabetic() || c == '-')
+}
+
+// Makes sure the polynomial string passed in has only allowed characters and isn't longer than the
+// max length. A string with several lines is a batch, where each line is validated as it's solved.
+#[allow(clippy::needless_pass_by_value)]
+fn poly_validator(s: String, max_length: usize) -> Result<(), String> {
+    if s.contains('\n') {
+        return Result::Ok(());
+    }
+    if looks_like_flag(&s) {
+        return Result::Err(format!("'{}' isn't a known flag.", s));
     }
+    let errors = validate_polynomial(&s, &[], max_length);
+    if errors.is_empty() {
+        Result::Ok(())
+    } else {
+        Result::Err(errors.join(" "))
+    }
+}
 
-    impl<S> From<S> for Subpolynomial
-    where
-        S: Into<String>,
-    {
-        // Handles turning a string into a Subpolynomial
-        fn from(value: S) -> Self {
-            let subpoly = value.into();
-
-            // Find the index of any 'x' character or the end of the expression. If not found, assume a 0th degree.
-            let coefficent_end_index = subpoly.find('x').unwrap_or_else(|| subpoly.len());
-
-            // Find the index of any '^' character plus 1
-            let degree_start_index = subpoly.find('^').and_then(|i| {
-                if i + 1 >= subpoly.len() {
-                    None
-                } else {
-                    Some(i + 1)
-                }
-            });
+// Makes sure the factored polynomial string passed in is valid, allowing parentheses.
+#[allow(clippy::needless_pass_by_value)]
+fn factored_validator(s: String, max_length: usize) -> Result<(), String> {
+    let errors = validate_polynomial(&s, &['(', ')'], max_length);
+    if errors.is_empty() {
+        Result::Ok(())
+    } else {
+        Result::Err(errors.join(" "))
+    }
+}
 
-            // Find the coefficent string
-            let mut coefficent = (&subpoly[0..coefficent_end_index]).to_owned();
+// Makes sure the max length passed in is a whole number no larger than the ceiling.
+#[allow(clippy::needless_pass_by_value)]
+fn max_length_validator(s: String) -> Result<(), String> {
+    match s.parse::<usize>() {
+        Ok(max_length) if max_length > 0 && max_length <= MAX_LENGTH_CEILING => Result::Ok(()),
+        _ => Result::Err(format!(
+            "Max length must be a whole number from 1 to {}.",
+            MAX_LENGTH_CEILING
+        )),
+    }
+}
 
-            // Try to take the degree string
-            let degree = degree_start_index.map(|i| (&subpoly[i..]));
+// Checks an argument against the max length given by --max-length, which can't be known while
+// clap is validating the arguments, exiting with an error if it's too long
+fn check_length(name: &str, s: &str, max_length: usize) {
+    if s.len() > max_length {
+        eprintln!("error: Invalid value for '{}': Polynomial too long.", name);
+        process::exit(1);
+    }
+}
 
-            // If the coefficent isn't found, or is only a sign, then add a one so it parses correctly.
-            if coefficent.is_empty() || coefficent == "-" || coefficent == "+" {
-                coefficent += "1";
-            }
+// Makes sure a coefficient passed in is a finite number.
+#[allow(clippy::needless_pass_by_value)]
+fn coefficient_validator(s: String) -> Result<(), String> {
+    match s.parse::<f64>() {
+        Ok(coefficient) if coefficient.is_finite() => Result::Ok(()),
+        _ => Result::Err("Coefficient is not a number.".to_owned()),
+    }
+}
 
-            // If the degree isn't found, check with the coefficent to determine what our degree should be.
-            let degree = degree.unwrap_or_else(|| {
-                if coefficent_end_index == subpoly.len() {
-                    "0"
-                } else {
-                    "1"
-                }
-            });
+// Makes sure the number of decimal places passed in is a whole number.
+#[allow(clippy::needless_pass_by_value)]
+fn decimals_validator(s: String) -> Result<(), String> {
+    match s.parse::<u32>() {
+        Ok(_) => Result::Ok(()),
+        _ => Result::Err("Decimal places must be a whole number.".to_owned()),
+    }
+}
 
-            // Parse and return the subpoly
-            Self {
-                coefficent: coefficent.parse().unwrap(),
-                degree: degree.parse().unwrap(),
-            }
-        }
+// Finds every reason a parsed polynomial would be solved lossily, for strict mode: terms above
+// degree 2, and coefficients left over from terms that almost cancelled out.
+fn strict_errors(polynomial: &Polynomial) -> Vec<String> {
+    let mut errors = Vec::new();
+    if polynomial.effective_degree() > 2 {
+        errors.push("Polynomials above degree 2 aren't allowed in strict mode.".to_owned());
+    }
+    if polynomial.has_negligible_coefficients() {
+        errors.push("Polynomial has a coefficient too close to zero to trust.".to_owned());
     }
+    errors
+}
+
+// Fills in a user supplied output template. The placeholders {root1}, {root2}, {a}, {b}, {c} and
+// {disc} are replaced with their values, and anything else is left as written.
+fn render_template(template: &str, polynomial: &Polynomial) -> String {
+    let roots = polynomial.roots();
+    template
+        .replace("{root1}", &format!("{:.4}", roots.0))
+        .replace("{root2}", &format!("{:.4}", roots.1))
+        .replace("{a}", &polynomial.coefficient(2).to_string())
+        .replace("{b}", &polynomial.coefficient(1).to_string())
+        .replace("{c}", &polynomial.coefficient(0).to_string())
+        .replace("{disc}", &polynomial.discriminant().to_string())
+}
+
+// Gets the root finder named by the --method flag, along with the highest degree it can solve if
+// it is limited
+fn root_finder(method: &str) -> (Box<dyn RootFinder>, Option<usize>) {
+    match method {
+        "cardano" => (Box::new(Cardano), Some(3)),
+        "newton" => (Box::new(Newton), None),
+        "companion" => (Box::new(CompanionMatrix), None),
+        _ => (Box::new(QuadraticFormula), Some(2)),
+    }
+}
+
+// Joins roots into a list like "1.0000, 3.0000, and 2.0000", formatting each one with the given
+// function
+fn format_roots(roots: &[f64], format_root: &dyn Fn(f64) -> String) -> String {
+    let formatted: Vec<String> = roots.iter().map(|root| format_root(*root)).collect();
+    match formatted.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{}, and {}", rest.join(", "), last),
+        _ => formatted.join(""),
+    }
+}
 
-    impl<S> From<S> for Polynomial
-    where
-        S: Into<String>,
+// Formats a root to four decimal places
+fn decimal_root(root: f64) -> String {
+    format!("{:.4}", root)
+}
+
+// Formats a root to four decimal places followed by the rational root it equals, if any, like
+// "0.5000 (1/2)"
+fn decimal_and_fraction_root(root: f64, rational_roots: &[Rational]) -> String {
+    let tolerance = 1e-9_f64 * root.abs().max(1_f64);
+    match rational_roots
+        .iter()
+        .find(|rational| (rational.to_f64() - root).abs() <= tolerance)
     {
-        fn from(value: S) -> Self {
-            // Strip any whitespace
-            let polynomial = value.into().replace(" ", "").replace("\t", "");
-
-            // Contains all the parsed subpolys
-            let mut subpoly_vector: Vec<Subpolynomial> = Vec::new();
-
-            // A buffer string used to build up a subpoly
-            let mut subpoly_buffer = String::new();
-            for i in 0..=polynomial.len() {
-                // Get the character at an index
-                let c = polynomial.as_bytes().get(i).map(|x| *x as char);
-                // If the character is a sign, or it is at the end of the string...
-                if (c.is_none() || c == Some('+') || c == Some('-')) && !subpoly_buffer.is_empty() {
-                    // Try and find the character before the subpoly, to get the sign of the coefficent
-                    let index: Option<usize> = i.checked_sub(subpoly_buffer.len() + 1);
-                    let sign_char = index.map(|i| polynomial.as_bytes()[i] as char);
-
-                    // Add the sign character to the subpoly string
-                    if sign_char.is_some()
-                        && (sign_char.unwrap() == '+' || sign_char.unwrap() == '-')
-                    {
-                        subpoly_buffer.insert(0, sign_char.unwrap());
-                    }
+        Some(rational) => format!("{:.4} ({})", root, rational),
+        None => decimal_root(root),
+    }
+}
 
-                    // Add the subpoly to the vec
-                    subpoly_vector.push(Subpolynomial::from(subpoly_buffer.as_str()));
-                    subpoly_buffer.clear();
-                }
+// Formats the roots found for a polynomial as the default output, where the polynomial is labeled
+// as the user wrote it
+fn format_result(polynomial_str: &str, polynomial: &Polynomial, roots: &[f64]) -> String {
+    format_result_with(polynomial_str, polynomial, roots, &decimal_root)
+}
 
-                if c.is_some() {
-                    let c = c.unwrap();
-                    if c.is_alphanumeric() || c == '^' || c == '.' {
-                        subpoly_buffer.push(c);
-                    }
-                }
-            }
+// Formats the roots found for a polynomial like format_result, formatting each root with the
+// given function
+fn format_result_with(
+    polynomial_str: &str,
+    polynomial: &Polynomial,
+    roots: &[f64],
+    format_root: &dyn Fn(f64) -> String,
+) -> String {
+    let real_roots: Vec<f64> = roots.iter().cloned().filter(|x| x.is_finite()).collect();
+    match polynomial.root_kind() {
+        RootKind::Infinite => {
+            return format!(
+                "({}) has infinitely many roots (identity 0 = 0)",
+                polynomial_str
+            )
+        }
+        RootKind::NoRoots => return format!("({}) has no roots", polynomial_str),
+        _ => (),
+    }
+    if polynomial.degree() == 1 {
+        format!(
+            "Factor of ({}) is {}",
+            polynomial_str,
+            format_root(roots[0])
+        )
+    } else if polynomial.has_double_root_at_zero() {
+        format!("({}) has a double root at 0", polynomial_str)
+    } else if polynomial.has_close_roots() && real_roots.len() == roots.len() {
+        format!(
+            "Factors of ({}) are {}, two distinct real roots (very close)",
+            polynomial_str,
+            format_roots(roots, format_root)
+        )
+    } else if real_roots.len() == roots.len() {
+        format!(
+            "Factors of ({}) are {}",
+            polynomial_str,
+            format_roots(roots, format_root)
+        )
+    } else if !real_roots.is_empty() {
+        format!(
+            "Real factors of ({}) are {}",
+            polynomial_str,
+            format_roots(&real_roots, format_root)
+        )
+    } else if polynomial.degree() == 2 && polynomial.coefficient(1) == 0_f64 {
+        // Without a linear term, the real part -B/2A of the roots is 0
+        format!("Factors of ({}) are imaginary", polynomial_str)
+    } else {
+        format!("Factors of ({}) are complex", polynomial_str)
+    }
+}
 
-            // Filter the subpoly vec by degree, then add up all the coefficent
-            Self {
-                a: subpoly_vector
-                    .iter()
-                    .filter(|x| x.degree == 2_u8)
-                    .fold(0_f64, |acc, x| acc + x.coefficent),
-                b: subpoly_vector
-                    .iter()
-                    .filter(|x| x.degree == 1_u8)
-                    .fold(0_f64, |acc, x| acc + x.coefficent),
-                c: subpoly_vector
-                    .iter()
-                    .filter(|x| x.degree == 0_u8)
-                    .fold(0_f64, |acc, x| acc + x.coefficent),
+// Formats the minimum or maximum value of a quadratic and where it occurs
+fn format_extremum(polynomial_str: &str, polynomial: &Polynomial) -> String {
+    match (polynomial.vertex(), polynomial.extreme_value()) {
+        (Some((x, _)), Some((extremum, y))) => format!(
+            "({}) has a {} value {:.4} at x={:.4}",
+            polynomial_str,
+            extremum,
+            y + 0_f64,
+            x + 0_f64
+        ),
+        _ => format!("({}) has no extremum", polynomial_str),
+    }
+}
+
+// Formats the intervals of x where a polynomial is positive as an inequality, like
+// "x < -1 or x > 1"
+fn format_positive_where(polynomial: &Polynomial) -> String {
+    let intervals: Vec<String> = polynomial
+        .positive_intervals()
+        .iter()
+        .map(|(lo, hi)| match (lo.is_finite(), hi.is_finite()) {
+            (true, true) => format!("{} < x < {}", format_root(*lo), format_root(*hi)),
+            (true, false) => format!("x > {}", format_root(*lo)),
+            (false, true) => format!("x < {}", format_root(*hi)),
+            (false, false) => "all x".to_owned(),
+        })
+        .collect();
+    if intervals.is_empty() {
+        "no x".to_owned()
+    } else {
+        intervals.join(" or ")
+    }
+}
+
+// Formats a table of x and f(x) from lo to hi in steps, or an error if the range is empty, the
+// step isn't positive or there would be more than MAX_TABLE_ROWS rows
+fn format_table(polynomial: &Polynomial, lo: f64, hi: f64, step: f64) -> Result<String, String> {
+    if step <= 0_f64 {
+        return Err("The step of the table must be positive.".to_owned());
+    }
+    if lo > hi {
+        return Err("The start of the table must not be after its end.".to_owned());
+    }
+    // Stepping by multiplying keeps rounding error from adding up, and the slack lets the end be
+    // reached despite it
+    let steps = ((hi - lo) / step + 1e-9_f64).floor();
+    if steps.is_nan() || steps >= MAX_TABLE_ROWS as f64 {
+        return Err(format!(
+            "The table can't have more than {} rows, so the step must be larger.",
+            MAX_TABLE_ROWS
+        ));
+    }
+    let steps = steps as usize;
+    let mut table = "x\tf(x)".to_owned();
+    for i in 0..=steps {
+        let x = lo + i as f64 * step;
+        table += &format!("\n{:.4}\t{:.4}", x, polynomial.evaluate(x) + 0_f64);
+    }
+    Ok(table)
+}
+
+// Formats the points where two polynomials intersect, labeled as the user wrote them
+fn format_intersections(
+    lhs_str: &str,
+    rhs_str: &str,
+    lhs: &Polynomial,
+    rhs: &Polynomial,
+) -> String {
+    if lhs == rhs {
+        return format!("({}) and ({}) are the same polynomial", lhs_str, rhs_str);
+    }
+    let points: Vec<String> = lhs
+        .intersections(rhs)
+        .iter()
+        .map(|(x, y)| format!("({:.4}, {:.4})", x, y))
+        .collect();
+    match points.split_last() {
+        None => format!("({}) and ({}) don't intersect", lhs_str, rhs_str),
+        Some((last, [])) => format!("({}) and ({}) intersect at {}", lhs_str, rhs_str, last),
+        Some((last, rest)) => format!(
+            "({}) and ({}) intersect at {}, and {}",
+            lhs_str,
+            rhs_str,
+            rest.join(", "),
+            last
+        ),
+    }
+}
+
+// Formats the result of solving a polynomial, or why it couldn't be solved
+fn format_solved(polynomial_str: &str, solved: Result<Solution, ParseError>) -> String {
+    match solved {
+        Ok(solution) if solution.polynomial.effective_degree() > 2 => {
+            "error: Polynomials above degree 2 aren't supported.".to_owned()
+        }
+        Ok(solution) => {
+            for warning in &solution.warnings {
+                eprintln!("warning: {}", warning);
             }
+            let (root1, root2) = solution.roots;
+            format_result(
+                polynomial_str,
+                &solution.polynomial.effective_polynomial(),
+                &[root1, root2],
+            )
         }
+        Err(error) => format!("error: {}", error),
     }
+}
 
+// Solves a problem from a config, formatting its result or why it couldn't be solved
+#[cfg(feature = "config")]
+fn format_problem(problem: &factoring::config::Problem) -> String {
+    format_solved(&problem.expr, problem.solve())
 }
 
-use clap::{App, Arg};
-use poly::Polynomial;
+// Validates and solves a single line of a batch of polynomials, formatting its result. When only
+// parsing, the canonical form of the polynomial is given instead of its roots.
+fn solve_line(line: &str, options: &ParseOptions, parse_only: bool, max_length: usize) -> String {
+    let errors = validate_polynomial(line, &[], max_length);
+    if !errors.is_empty() {
+        format!("error: {}", errors.join(" "))
+    } else if parse_only {
+        match Polynomial::parse_with(line, options) {
+            Ok(polynomial) => polynomial.to_string(),
+            Err(error) => format!("error: {}", error),
+        }
+    } else {
+        format_solved(line, factoring::solve_with(line, options))
+    }
+}
+
+// Gets the polynomials in a batch, one per line, skipping blank lines
+fn batch_lines(input: &str) -> Vec<&str> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+// Solves a batch of polynomials one at a time, formatting the result of each line in order
+fn solve_lines(
+    input: &str,
+    options: &ParseOptions,
+    parse_only: bool,
+    max_length: usize,
+) -> Vec<String> {
+    batch_lines(input)
+        .iter()
+        .map(|line| solve_line(line, options, parse_only, max_length))
+        .collect()
+}
 
-// Makes sure the polynomial string passed in has only allowed characters and isn't too long.
+// Solves a batch of polynomials across threads, formatting the result of each line in order
+#[cfg(feature = "parallel")]
+fn solve_lines_parallel(
+    input: &str,
+    options: &ParseOptions,
+    parse_only: bool,
+    max_length: usize,
+) -> Vec<String> {
+    use rayon::prelude::*;
+    batch_lines(input)
+        .par_iter()
+        .map(|line| solve_line(line, options, parse_only, max_length))
+        .collect()
+}
+
+// Reads all of stdin, giving up with a TimedOut error if it isn't closed within the timeout. The
+// read happens on its own thread, which is left blocked if it times out.
+fn read_stdin(timeout: Option<Duration>) -> io::Result<String> {
+    let timeout = match timeout {
+        Some(timeout) => timeout,
+        None => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            return Ok(input);
+        }
+    };
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let mut input = String::new();
+        let result = io::stdin().read_to_string(&mut input).map(|_| input);
+        let _ = sender.send(result);
+    });
+    receiver.recv_timeout(timeout).unwrap_or_else(|_| {
+        Err(io::Error::new(
+            io::ErrorKind::TimedOut,
+            format!(
+                "Timed out after {}ms waiting for stdin.",
+                timeout.as_millis()
+            ),
+        ))
+    })
+}
+
+// Makes sure the stdin timeout passed in is a positive whole number of milliseconds. A timeout of
+// 0 would give up before even input that's already waiting could be read.
 #[allow(clippy::needless_pass_by_value)]
-fn poly_validator(s: String) -> Result<(), String> {
-    if !s.is_ascii() {
-        return Result::Err("Polynomial not ASCII.".to_owned());
+fn timeout_validator(s: String) -> Result<(), String> {
+    match s.parse::<u64>() {
+        Ok(0) => Result::Err("Timeout must be at least 1 millisecond.".to_owned()),
+        Ok(_) => Result::Ok(()),
+        _ => Result::Err("Timeout must be a whole number of milliseconds.".to_owned()),
     }
-    if s.len() > 100 {
-        return Result::Err("Polynomial too long.".to_owned());
-    }
-    let allowed_chars = [
-        '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '+', '-', '^', '.', 'x', ' ', '\t',
-    ];
-    for c in s.chars() {
-        if !allowed_chars.contains(&c) {
-            return Result::Err("Polynomial has unsupported characters or is not basic.".to_owned());
-        }
+}
+
+// Writes a line of output, exiting with an error if it can't be written
+fn write_output(out: &mut dyn Write, output: impl fmt::Display) {
+    if let Err(error) = writeln!(out, "{}", output) {
+        eprintln!("error: Couldn't write output: {}", error);
+        process::exit(1);
     }
-    Result::Ok(())
+}
+
+// Prints a parse error, highlighting where it occurred in the input when diagnostics are enabled
+#[cfg(feature = "miette")]
+fn report_parse_error(error: ParseError, input: &str) {
+    eprintln!(
+        "{:?}",
+        miette::Report::new(error).with_source_code(input.to_owned())
+    );
+}
+
+// Prints a parse error
+#[cfg(not(feature = "miette"))]
+fn report_parse_error(error: ParseError, _input: &str) {
+    eprintln!("error: {}", error);
 }
 
 fn main() {
     // Get the commandline arguments
-    let matches = App::new("Factoring")
-        .version("1.0")
+    let app = App::new("Factoring")
+        .version(factoring::crate_version())
         .author("Eric Pfister")
         .about("Factors basic polynomials into their (real) factors.")
-        .usage("factoring <POLYNOMIAL>")
+        .usage(
+            "factoring [OPTIONS] <POLYNOMIAL>\n    \
+             factoring [OPTIONS] --coeffs <A> <B> <C>\n    \
+             factoring expand <FACTORED>\n    \
+             factoring intersect <LHS> <RHS>",
+        )
+        .setting(AppSettings::SubcommandsNegateReqs)
+        .setting(AppSettings::AllowLeadingHyphen)
+        .subcommand(
+            SubCommand::with_name("expand")
+                .about("Expands a factored polynomial like (x + 2)(x - 3) into its standard form")
+                .arg(
+                    Arg::with_name("FACTORED")
+                        .required(true)
+                        .validator(|s| factored_validator(s, MAX_LENGTH_CEILING))
+                        .help("A product of factors in the form of A(x + B)(x + C)"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("intersect")
+                .about("Finds the points where two polynomials intersect")
+                .setting(AppSettings::AllowLeadingHyphen)
+                .arg(
+                    Arg::with_name("LHS")
+                        .required(true)
+                        .validator(|s| poly_validator(s, MAX_LENGTH_CEILING))
+                        .help("The first polynomial"),
+                )
+                .arg(
+                    Arg::with_name("RHS")
+                        .required(true)
+                        .validator(|s| poly_validator(s, MAX_LENGTH_CEILING))
+                        .help("The second polynomial"),
+                ),
+        )
         .arg(
             Arg::with_name("POLYNOMIAL")
-                .required(true)
-                .validator(poly_validator)
-                .help("A basic polynomial in the form of Ax^2 + Bx + C"),
+                .required_unless_one(&["coeffs", "config", "file"])
+                .conflicts_with_all(&["coeffs", "config", "file"])
+                .validator(|s| poly_validator(s, MAX_LENGTH_CEILING))
+                .help("A basic polynomial in the form of Ax^2 + Bx + C, or - to read them from stdin"),
+        )
+        .arg(
+            Arg::with_name("coeffs")
+                .long("coeffs")
+                .value_names(&["A", "B", "C"])
+                .allow_hyphen_values(true)
+                .validator(coefficient_validator)
+                .help("The coefficients of the polynomial, highest degree first, instead of POLYNOMIAL"),
+        )
+        .arg(
+            Arg::with_name("table")
+                .long("table")
+                .value_names(&["LO", "HI", "STEP"])
+                .allow_hyphen_values(true)
+                .validator(coefficient_validator)
+                .help("Prints a table of x and f(x) from LO to HI in steps of STEP"),
+        )
+        .arg(
+            Arg::with_name("file")
+                .long("file")
+                .takes_value(true)
+                .value_name("FILE")
+                .conflicts_with("coeffs")
+                .help("Solves each polynomial in a file, one per line, instead of POLYNOMIAL"),
+        )
+        .arg(
+            Arg::with_name("max-length")
+                .long("max-length")
+                .takes_value(true)
+                .value_name("N")
+                .global(true)
+                .validator(max_length_validator)
+                .help("The most characters a polynomial can have, up to 10000 [default: 100]"),
+        )
+        .arg(
+            Arg::with_name("stdin-timeout")
+                .long("stdin-timeout")
+                .takes_value(true)
+                .value_name("MS")
+                .validator(timeout_validator)
+                .help("Gives up reading polynomials from stdin after MS milliseconds"),
+        )
+        .arg(
+            Arg::with_name("output-file")
+                .long("output-file")
+                .takes_value(true)
+                .value_name("FILE")
+                .help("Writes the results to a file instead of stdout"),
+        )
+        .arg(
+            Arg::with_name("parse-only")
+                .long("parse-only")
+                .help("Prints the canonical form of the polynomial without solving it, to check it's valid"),
+        )
+        .arg(
+            Arg::with_name("plot-svg")
+                .long("plot-svg")
+                .takes_value(true)
+                .value_name("FILE")
+                .help("Also plots the polynomial and its real roots to an SVG file"),
+        )
+        .arg(
+            Arg::with_name("count-real-roots")
+                .long("count-real-roots")
+                .help("Prints only how many distinct real roots the polynomial has"),
+        )
+        .arg(
+            Arg::with_name("positive-where")
+                .long("positive-where")
+                .help("Prints the intervals of x where the polynomial is positive, like x < -1 or x > 1"),
+        )
+        .arg(
+            Arg::with_name("strict")
+                .long("strict")
+                .help("Rejects polynomials that would be solved lossily, like ones above degree 2"),
+        )
+        .arg(
+            Arg::with_name("time")
+                .long("time")
+                .help("Prints how long parsing and finding the roots took to stderr"),
+        )
+        .arg(
+            Arg::with_name("positive-leading")
+                .long("positive-leading")
+                .requires("parse-only")
+                .help("Factors out -1 when the leading coefficient is negative, like -(x^2 - 4)"),
+        )
+        .arg(
+            Arg::with_name("format")
+                .long("format")
+                .takes_value(true)
+                .possible_values(&["plain", "latex", "json"])
+                .default_value("plain")
+                .help("The output format of the factors"),
+        )
+        .arg(
+            Arg::with_name("method")
+                .long("method")
+                .takes_value(true)
+                .possible_values(&["quadratic", "cardano", "newton", "companion"])
+                .conflicts_with_all(&["template", "factored", "rational-check", "extremum", "surd"])
+                .help("The method used to find the roots, where cardano solves cubics, and newton and companion solve any degree"),
+        )
+        .arg(
+            Arg::with_name("template")
+                .long("template")
+                .takes_value(true)
+                .help("Prints the result using a template with {root1}, {root2}, {a}, {b}, {c} and {disc} placeholders"),
+        )
+        .arg(
+            Arg::with_name("factored")
+                .long("factored")
+                .help("Prints the polynomial as the product of its factors"),
+        )
+        .arg(
+            Arg::with_name("round-coeffs")
+                .long("round-coeffs")
+                .takes_value(true)
+                .value_name("N")
+                .validator(decimals_validator)
+                .help("Rounds the coefficients to N decimal places before solving"),
         )
-        .get_matches();
+        .arg(
+            Arg::with_name("fractions")
+                .long("fractions")
+                .help("Prints each rational root as a fraction after its decimal, like 0.5000 (1/2)"),
+        )
+        .arg(
+            Arg::with_name("surd")
+                .long("surd")
+                .help("Prints irrational roots exactly in simplified radical form, like ±2√2"),
+        )
+        .arg(
+            Arg::with_name("implicit-exponent")
+                .long("implicit-exponent")
+                .help("Reads digits right after the x as its degree, so 3x2 means 3x^2"),
+        )
+        .arg(
+            Arg::with_name("decimal-comma")
+                .long("decimal-comma")
+                .help("Reads commas as decimal points, so 3,5 means 3.5, instead of as thousands separators"),
+        )
+        .arg(
+            Arg::with_name("extremum")
+                .long("extremum")
+                .help("Prints the minimum or maximum value of the quadratic and where it occurs"),
+        )
+        .arg(
+            Arg::with_name("rational-check")
+                .long("rational-check")
+                .help("Prints whether the polynomial factors over the rationals"),
+        );
+    #[cfg(feature = "exact")]
+    let app = app.arg(
+        Arg::with_name("exact")
+            .long("exact")
+            .conflicts_with_all(&["factored", "fractions", "surd"])
+            .help("Computes rational factors exactly, with arbitrary precision"),
+    );
+    #[cfg(feature = "config")]
+    let app = app.arg(
+        Arg::with_name("config")
+            .long("config")
+            .takes_value(true)
+            .value_name("FILE")
+            .conflicts_with("coeffs")
+            .help("Solves each problem listed in a TOML config instead of POLYNOMIAL"),
+    );
+    #[cfg(feature = "parallel")]
+    let app = app.arg(
+        Arg::with_name("parallel")
+            .long("parallel")
+            .requires("file")
+            .help("Solves the lines of the file across multiple threads"),
+    );
+    let matches = app.get_matches();
+    let max_length = matches
+        .value_of("max-length")
+        .map_or(DEFAULT_MAX_LENGTH, |x| x.parse().unwrap());
 
-    let polynomial_str: &str = matches.value_of("POLYNOMIAL").unwrap();
-    let polynomial: Polynomial = Polynomial::from(polynomial_str);
-    let roots = polynomial.roots();
-    if roots.0.is_finite() && roots.1.is_finite() {
-        println!(
-            "Factors of ({}) are {:.4}, and {:.4}",
-            polynomial_str, roots.0, roots.1
+    let mut out: Box<dyn Write> = match matches.value_of("output-file") {
+        Some(path) => match File::create(path) {
+            Ok(file) => Box::new(file),
+            Err(error) => {
+                eprintln!("error: Couldn't open output file {}: {}", path, error);
+                process::exit(1);
+            }
+        },
+        None => Box::new(io::stdout()),
+    };
+
+    if let Some(matches) = matches.subcommand_matches("expand") {
+        let factored_str = matches.value_of("FACTORED").unwrap();
+        check_length("<FACTORED>", factored_str, max_length);
+        match Polynomial::from_factored(factored_str) {
+            Ok(polynomial) => write_output(&mut out, polynomial),
+            Err(error) => {
+                report_parse_error(error, factored_str);
+                process::exit(1);
+            }
+        }
+        return;
+    }
+
+    if let Some(matches) = matches.subcommand_matches("intersect") {
+        let mut polynomials = Vec::new();
+        for name in &["LHS", "RHS"] {
+            let polynomial_str = matches.value_of(name).unwrap();
+            check_length(&format!("<{}>", name), polynomial_str, max_length);
+            match polynomial_str.parse::<Polynomial>() {
+                Ok(polynomial) => polynomials.push(polynomial),
+                Err(error) => {
+                    report_parse_error(error, polynomial_str);
+                    process::exit(1);
+                }
+            }
+        }
+        write_output(
+            &mut out,
+            format_intersections(
+                matches.value_of("LHS").unwrap(),
+                matches.value_of("RHS").unwrap(),
+                &polynomials[0],
+                &polynomials[1],
+            ),
         );
+        return;
+    }
+
+    let options = ParseOptions {
+        implicit_exponent: matches.is_present("implicit-exponent"),
+        decimal_comma: matches.is_present("decimal-comma"),
+    };
+    let parse_only = matches.is_present("parse-only");
+    if let Some(path) = matches.value_of("file") {
+        let input = match std::fs::read_to_string(path) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("error: {}", error);
+                process::exit(1);
+            }
+        };
+        #[cfg(feature = "parallel")]
+        let lines = if matches.is_present("parallel") {
+            solve_lines_parallel(&input, &options, parse_only, max_length)
+        } else {
+            solve_lines(&input, &options, parse_only, max_length)
+        };
+        #[cfg(not(feature = "parallel"))]
+        let lines = solve_lines(&input, &options, parse_only, max_length);
+        for line in lines {
+            write_output(&mut out, line);
+        }
+        return;
+    }
+
+    // A polynomial argument of "-" reads the polynomials from stdin, one per line
+    if matches.value_of("POLYNOMIAL") == Some("-") {
+        let timeout = matches
+            .value_of("stdin-timeout")
+            .map(|x| Duration::from_millis(x.parse().unwrap()));
+        let input = match read_stdin(timeout) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("error: {}", error);
+                process::exit(1);
+            }
+        };
+        for line in solve_lines(&input, &options, parse_only, max_length) {
+            write_output(&mut out, line);
+        }
+        return;
+    }
+
+    // A polynomial argument with several lines is solved line by line, like a file
+    if let Some(input) = matches.value_of("POLYNOMIAL").filter(|x| x.contains('\n')) {
+        for line in solve_lines(input, &options, parse_only, max_length) {
+            write_output(&mut out, line);
+        }
+        return;
+    }
+
+    #[cfg(feature = "config")]
+    {
+        if let Some(path) = matches.value_of("config") {
+            let problems = std::fs::read_to_string(path)
+                .map_err(|error| error.to_string())
+                .and_then(|config| {
+                    factoring::config::load_problems(&config).map_err(|error| error.to_string())
+                });
+            match problems {
+                Ok(problems) => {
+                    for problem in problems {
+                        write_output(
+                            &mut out,
+                            format_args!("{}: {}", problem.name, format_problem(&problem)),
+                        );
+                    }
+                }
+                Err(error) => {
+                    eprintln!("error: {}", error);
+                    process::exit(1);
+                }
+            }
+            return;
+        }
+    }
+
+    let time = matches.is_present("time");
+    let parse_start = Instant::now();
+    let (polynomial_str, polynomial): (String, Polynomial) = match matches.values_of("coeffs") {
+        Some(values) => {
+            let values: Vec<&str> = values.collect();
+            let coefficients: Vec<f64> = values.iter().map(|x| x.parse().unwrap()).collect();
+            (
+                values.join(" "),
+                Polynomial::from_coefficients(&coefficients),
+            )
+        }
+        None => {
+            let polynomial_str = matches.value_of("POLYNOMIAL").unwrap();
+            check_length("<POLYNOMIAL>", polynomial_str, max_length);
+            match Polynomial::parse_with(polynomial_str, &options) {
+                Ok(polynomial) => {
+                    for warning in factoring::parse_warnings(polynomial_str, &options) {
+                        eprintln!("warning: {}", warning);
+                    }
+                    (polynomial_str.to_owned(), polynomial)
+                }
+                Err(error) => {
+                    report_parse_error(error, polynomial_str);
+                    process::exit(1);
+                }
+            }
+        }
+    };
+    let polynomial = match matches.value_of("round-coeffs") {
+        Some(decimals) => polynomial.round_coefficients(decimals.parse().unwrap()),
+        None => polynomial,
+    };
+    if time {
+        eprintln!("Parsed in {}µs", parse_start.elapsed().as_micros());
+    }
+    if matches.is_present("strict") {
+        let errors = strict_errors(&polynomial);
+        if !errors.is_empty() {
+            eprintln!("error: {}", errors.join(" "));
+            process::exit(1);
+        }
+    }
+    // Leftovers of leading terms that cancelled out would add a spurious root, or make the
+    // polynomial look like a higher degree than it is
+    let polynomial = polynomial.effective_polynomial();
+    if parse_only {
+        if matches.is_present("positive-leading") {
+            write_output(&mut out, polynomial.to_positive_leading_string());
+        } else {
+            write_output(&mut out, &polynomial);
+        }
+        return;
+    }
+    if matches.is_present("count-real-roots") {
+        match polynomial.count_distinct_real_roots() {
+            Some(count) => write_output(&mut out, count),
+            None => write_output(&mut out, "infinite"),
+        }
+        return;
+    }
+    if matches.is_present("positive-where") {
+        write_output(&mut out, format_positive_where(&polynomial));
+        return;
+    }
+    if let Some(values) = matches.values_of("table") {
+        let values: Vec<f64> = values.map(|x| x.parse().unwrap()).collect();
+        match format_table(&polynomial, values[0], values[1], values[2]) {
+            Ok(table) => write_output(&mut out, table),
+            Err(error) => {
+                eprintln!("error: {}", error);
+                process::exit(1);
+            }
+        }
+        return;
+    }
+    let (finder, max_degree) = root_finder(matches.value_of("method").unwrap_or("quadratic"));
+    let max_degree = if matches.value_of("format") != Some("plain") {
+        Some(2)
     } else {
-        println!("Factors of ({}) are imaginary", polynomial_str);
+        max_degree
+    };
+    if let Some(max_degree) = max_degree.filter(|max| polynomial.degree() > *max) {
+        eprintln!(
+            "error: Polynomials above degree {} aren't supported.",
+            max_degree
+        );
+        process::exit(1);
+    }
+    if let Some(path) = matches.value_of("plot-svg") {
+        if let Err(error) = std::fs::write(path, polynomial.to_svg()) {
+            eprintln!("error: Couldn't write plot {}: {}", path, error);
+            process::exit(1);
+        }
+    }
+    if matches.value_of("format") == Some("latex") {
+        write_output(&mut out, polynomial.to_latex());
+        return;
+    }
+    if matches.value_of("format") == Some("json") {
+        write_output(&mut out, Solution::new(polynomial).to_json());
+        return;
     }
+    if matches.is_present("rational-check") {
+        write_output(&mut out, polynomial.factors_over_rationals());
+        return;
+    }
+    if matches.is_present("extremum") {
+        write_output(&mut out, format_extremum(&polynomial_str, &polynomial));
+        return;
+    }
+    if let Some(template) = matches.value_of("template") {
+        write_output(&mut out, render_template(template, &polynomial));
+        return;
+    }
+    #[cfg(feature = "exact")]
+    {
+        // Polynomials that can't be represented exactly, like those with square roots, and
+        // roots that aren't rational are computed as usual. Coefficients given as floats or
+        // rounded have already lost their exact values, so those are computed as usual too.
+        let exact = matches.is_present("exact")
+            && !matches.is_present("coeffs")
+            && !matches.is_present("round-coeffs");
+        let roots = matches
+            .value_of("POLYNOMIAL")
+            .filter(|_| exact)
+            .and_then(|input| ExactPolynomial::parse(input, &options).ok())
+            .and_then(|exact| exact.roots());
+        if let Some(roots) = roots {
+            write_output(
+                &mut out,
+                format_args!(
+                    "Factors of ({}) are {}, and {}",
+                    polynomial_str, roots.0, roots.1
+                ),
+            );
+            return;
+        }
+    }
+    if matches.is_present("factored") {
+        match polynomial.factored_form() {
+            Some(factored) => write_output(
+                &mut out,
+                format_args!("Factored form of ({}) is {}", polynomial_str, factored),
+            ),
+            None => write_output(
+                &mut out,
+                format_args!("({}) has no real factored form", polynomial_str),
+            ),
+        }
+        return;
+    }
+    // Rational and complex roots have no surd form, so they're printed as usual
+    if let (true, Some(surd)) = (matches.is_present("surd"), polynomial.surd_form()) {
+        write_output(
+            &mut out,
+            format_args!("Factors of ({}) are {}", polynomial_str, surd),
+        );
+        return;
+    }
+    let solve_start = Instant::now();
+    let roots = finder.find_roots(&polynomial);
+    if time {
+        eprintln!("Solved in {}µs", solve_start.elapsed().as_micros());
+    }
+    if matches.is_present("fractions") {
+        // Only real roots can be fractions, so the bounded candidate search is skipped otherwise
+        let rational_roots = if roots.iter().any(|root| root.is_finite()) {
+            polynomial.rational_roots()
+        } else {
+            Vec::new()
+        };
+        write_output(
+            &mut out,
+            format_result_with(&polynomial_str, &polynomial, &roots, &|root| {
+                decimal_and_fraction_root(root, &rational_roots)
+            }),
+        );
+        return;
+    }
+    write_output(
+        &mut out,
+        format_result(&polynomial_str, &polynomial, &roots),
+    );
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::poly::*;
+    use super::*;
 
-    // Tests a basic polynomial
+    // Tests filling in an output template
     #[test]
-    fn basic_polynomial() {
-        let string = "x^2 + 4x + 4";
-        let poly = Polynomial::from(string);
-        let roots = poly.roots();
+    fn template_output() {
+        let polynomial = Polynomial::from("x^2+4x+4");
+        assert_eq!(
+            render_template("{root1},{root2} ({a} {b} {c}, {disc}) {other}", &polynomial),
+            "-2.0000,-2.0000 (1 4 4, 0) {other}"
+        );
+    }
 
-        assert_eq!(roots, (-2_f64, -2_f64));
+    // Tests selecting a root finding strategy by name
+    #[test]
+    fn select_root_finder() {
+        let polynomial = Polynomial::from("x^3 - 6x^2 + 11x - 6");
+        let (finder, max_degree) = root_finder("newton");
+        assert_eq!(max_degree, None);
+        assert_eq!(
+            format_roots(&finder.find_roots(&polynomial), &decimal_root),
+            "1.0000, 3.0000, and 2.0000"
+        );
+        let (_, max_degree) = root_finder("cardano");
+        assert_eq!(max_degree, Some(3));
+        let (finder, max_degree) = root_finder("quadratic");
+        assert_eq!(max_degree, Some(2));
+        assert_eq!(
+            finder.find_roots(&Polynomial::from("x^2 - 1")),
+            vec![1_f64, -1_f64]
+        );
     }
-    // Tests a polynomial who's roots are imaginary
+
+    // Tests formatting the default output for each kind of root
     #[test]
-    fn imaginary_polynomial() {
-        let string = "5x^2 + 4x + 4";
-        let poly = Polynomial::from(string);
-        let roots = poly.roots();
+    fn result_output() {
+        let outputs: Vec<String> = ["x^2 - 1", "x^2 + 1", "2x + 1", "x - x", "3"]
+            .iter()
+            .map(|input| {
+                let polynomial = Polynomial::from(*input);
+                let (root1, root2) = polynomial.roots();
+                format_result(input, &polynomial, &[root1, root2])
+            })
+            .collect();
+        insta::assert_snapshot!(outputs.join("\n"));
+    }
 
-        assert!(!roots.0.is_finite() || !roots.1.is_finite());
+    // Tests that a cancelled leading term is solved as a lower degree polynomial
+    #[test]
+    fn cancelled_term_output() {
+        let outputs: Vec<String> = ["x^2 - x^2 + 2x - 1", "x^2 - x^2"]
+            .iter()
+            .map(|input| {
+                let polynomial = Polynomial::from(*input);
+                format_result(
+                    input,
+                    &polynomial,
+                    &QuadraticFormula.find_roots(&polynomial),
+                )
+            })
+            .collect();
+        assert_eq!(
+            outputs,
+            vec![
+                "Factor of (x^2 - x^2 + 2x - 1) is 0.5000",
+                "(x^2 - x^2) has infinitely many roots (identity 0 = 0)"
+            ]
+        );
+
+        // Terms that leave a tiny leftover when cancelling are solved the same way
+        assert_eq!(
+            solve_lines(
+                "0.1x^2 + 0.2x^2 - 0.3x^2 + 3x + 1",
+                &ParseOptions::default(),
+                false,
+                DEFAULT_MAX_LENGTH
+            ),
+            vec!["Factor of (0.1x^2 + 0.2x^2 - 0.3x^2 + 3x + 1) is -0.3333"]
+        );
+        assert_eq!(
+            solve_lines(
+                "0.1x^3 + 0.2x^3 - 0.3x^3 + x^2 - 1",
+                &ParseOptions::default(),
+                false,
+                DEFAULT_MAX_LENGTH
+            ),
+            vec!["Factors of (0.1x^3 + 0.2x^3 - 0.3x^3 + x^2 - 1) are 1.0000, and -1.0000"]
+        );
     }
 
-    // Tests the conversion of string to polynomial
+    // Tests the checks of strict mode
     #[test]
-    fn format_polynomial() {
-        let string =
-            "x^2 + -0.5x + -2.5x + 2.5x + 0.5x + 4x + 8x - 4x -+-4x + 4 + 12 --+-8         -4";
-        let poly = Polynomial::from(string);
-        let roots = poly.roots();
-        assert_eq!(roots, (-2_f64, -2_f64));
+    fn strict_checks() {
+        assert_eq!(
+            strict_errors(&Polynomial::from("x^3 + 1")),
+            vec!["Polynomials above degree 2 aren't allowed in strict mode."]
+        );
+        assert!(strict_errors(&Polynomial::from("x^2 + 1")).is_empty());
+        assert_eq!(
+            strict_errors(&Polynomial::from("x^2 + 0.1x + 0.2x - 0.3x")),
+            vec!["Polynomial has a coefficient too close to zero to trust."]
+        );
+    }
+
+    // Tests printing a table of values
+    #[test]
+    fn table_output() {
+        assert_eq!(
+            format_table(&Polynomial::from("x^2"), 0_f64, 2_f64, 1_f64),
+            Ok("x\tf(x)\n0.0000\t0.0000\n1.0000\t1.0000\n2.0000\t4.0000".to_owned())
+        );
+        assert_eq!(
+            format_table(&Polynomial::from("x"), 0_f64, 0.3_f64, 0.1_f64)
+                .unwrap()
+                .lines()
+                .count(),
+            5
+        );
+        assert!(format_table(&Polynomial::from("x"), 0_f64, 1_f64, 0_f64).is_err());
+        assert!(format_table(&Polynomial::from("x"), 1_f64, 0_f64, 1_f64).is_err());
+        assert_eq!(
+            format_table(&Polynomial::from("x"), 0_f64, 1_f64, 1e-9_f64),
+            Err(
+                "The table can't have more than 10000 rows, so the step must be larger.".to_owned()
+            )
+        );
+        assert_eq!(
+            format_table(&Polynomial::from("x"), 0_f64, 9_999_f64, 1_f64)
+                .unwrap()
+                .lines()
+                .count(),
+            10_001
+        );
+    }
+
+    // Tests the message for a quadratic whose only root is a double root at 0
+    #[test]
+    fn double_root_at_zero_output() {
+        let polynomial = Polynomial::from("3x^2");
+        assert_eq!(
+            format_result(
+                "3x^2",
+                &polynomial,
+                &QuadraticFormula.find_roots(&polynomial)
+            ),
+            "(3x^2) has a double root at 0"
+        );
+    }
+
+    // Tests formatting where a polynomial is positive as an inequality
+    #[test]
+    fn positive_where_output() {
+        let outputs: Vec<String> = ["x^2 - 1", "-x^2 + 2.25", "x^2 + 1", "-x^2 - 1", "x^2"]
+            .iter()
+            .map(|input| format_positive_where(&Polynomial::from(*input)))
+            .collect();
+        assert_eq!(
+            outputs,
+            vec![
+                "x < -1 or x > 1",
+                "-1.5 < x < 1.5",
+                "all x",
+                "no x",
+                "x < 0 or x > 0"
+            ]
+        );
+    }
+
+    // Tests printing rational roots as fractions after their decimals
+    #[test]
+    fn fraction_output() {
+        let outputs: Vec<String> = ["2x^2 - 3x + 1", "x^2 - 2", "3x - 1"]
+            .iter()
+            .map(|input| {
+                let polynomial = Polynomial::from(*input);
+                let rational_roots = polynomial.rational_roots();
+                format_result_with(
+                    input,
+                    &polynomial,
+                    &QuadraticFormula.find_roots(&polynomial),
+                    &|root| decimal_and_fraction_root(root, &rational_roots),
+                )
+            })
+            .collect();
+        assert_eq!(
+            outputs,
+            vec![
+                "Factors of (2x^2 - 3x + 1) are 1.0000 (1), and 0.5000 (1/2)",
+                "Factors of (x^2 - 2) are 1.4142, and -1.4142",
+                "Factor of (3x - 1) is 0.3333 (1/3)"
+            ]
+        );
+    }
+
+    // Tests telling apart purely imaginary roots from complex ones with a real part
+    #[test]
+    fn complex_output() {
+        let outputs: Vec<String> = ["x^2 + 1", "x^2 + x + 1", "5x^2 + 4x + 4", "-2x^2 - 8"]
+            .iter()
+            .map(|input| {
+                let polynomial = Polynomial::from(*input);
+                format_result(
+                    input,
+                    &polynomial,
+                    &QuadraticFormula.find_roots(&polynomial),
+                )
+            })
+            .collect();
+        assert_eq!(
+            outputs,
+            vec![
+                "Factors of (x^2 + 1) are imaginary",
+                "Factors of (x^2 + x + 1) are complex",
+                "Factors of (5x^2 + 4x + 4) are complex",
+                "Factors of (-2x^2 - 8) are imaginary"
+            ]
+        );
+    }
+
+    // Tests that roots too close to tell apart aren't passed off as a double root
+    #[test]
+    fn close_roots_output() {
+        let polynomial = Polynomial::from("x^2 - 2x + 0.9999999999");
+        assert_eq!(
+            format_result(
+                "x^2 - 2x + 0.9999999999",
+                &polynomial,
+                &QuadraticFormula.find_roots(&polynomial)
+            ),
+            "Factors of (x^2 - 2x + 0.9999999999) are 1.0000, and 1.0000, two distinct real \
+             roots (very close)"
+        );
+    }
+
+    // Tests solving a batch of polynomials line by line
+    #[test]
+    fn batch_output() {
+        assert_eq!(
+            solve_lines(
+                "x^2 - 1\n\n  2x + 1\nx^2 * 2\nx^3\n",
+                &ParseOptions::default(),
+                false,
+                DEFAULT_MAX_LENGTH
+            ),
+            vec![
+                "Factors of (x^2 - 1) are 1.0000, and -1.0000",
+                "Factor of (2x + 1) is -0.5000",
+                "error: Polynomial has unsupported character '*' at position 4.",
+                "error: Polynomials above degree 2 aren't supported.",
+            ]
+        );
+    }
+
+    // Tests only parsing a batch of polynomials
+    #[test]
+    fn parse_only_batch() {
+        assert_eq!(
+            solve_lines(
+                "x^2+4x+4\nx^3 - x\nx^2.5",
+                &ParseOptions::default(),
+                true,
+                DEFAULT_MAX_LENGTH
+            ),
+            vec![
+                "x^2 + 4x + 4",
+                "x^3 - x",
+                "error: Invalid degree in term \"x^2.5\" at position 0."
+            ]
+        );
+    }
+
+    // Tests that solving a batch across threads keeps the results in order
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_batch() {
+        let input: Vec<String> = (1..200).map(|i| format!("x^2 - {}", i)).collect();
+        let input = input.join("\n");
+        assert_eq!(
+            solve_lines_parallel(&input, &ParseOptions::default(), false, DEFAULT_MAX_LENGTH),
+            solve_lines(&input, &ParseOptions::default(), false, DEFAULT_MAX_LENGTH)
+        );
+    }
+
+    // Tests formatting the minimum or maximum of a quadratic
+    #[test]
+    fn extremum_output() {
+        assert_eq!(
+            format_extremum("x^2 + 4x + 4", &Polynomial::from("x^2 + 4x + 4")),
+            "(x^2 + 4x + 4) has a minimum value 0.0000 at x=-2.0000"
+        );
+        assert_eq!(
+            format_extremum("-x^2 + 1", &Polynomial::from("-x^2 + 1")),
+            "(-x^2 + 1) has a maximum value 1.0000 at x=0.0000"
+        );
+        assert_eq!(
+            format_extremum("x + 1", &Polynomial::from("x + 1")),
+            "(x + 1) has no extremum"
+        );
+    }
+
+    // Tests that every unsupported character is reported
+    #[test]
+    fn validator_reports_all_errors() {
+        assert_eq!(
+            poly_validator("x^2 + 4x + 4".to_owned(), DEFAULT_MAX_LENGTH),
+            Ok(())
+        );
+        assert_eq!(
+            poly_validator("x^2 * 4y + 4".to_owned(), DEFAULT_MAX_LENGTH),
+            Err("Polynomial has unsupported character '*' at position 4. \
+                 Polynomial has unsupported character 'y' at position 7."
+                .to_owned())
+        );
+        assert_eq!(
+            poly_validator("(3*2)x^2 + (1/2)".to_owned(), DEFAULT_MAX_LENGTH),
+            Ok(())
+        );
+    }
+
+    // Tests telling mistyped flags apart from polynomials with a leading minus
+    #[test]
+    fn mistyped_flags() {
+        assert_eq!(
+            poly_validator("--factord".to_owned(), DEFAULT_MAX_LENGTH),
+            Err("'--factord' isn't a known flag.".to_owned())
+        );
+        assert!(looks_like_flag("-factored"));
+        assert!(!looks_like_flag("-x"));
+        assert!(!looks_like_flag("-x^2 + 1"));
+        assert!(!looks_like_flag("--x^2"));
+        assert!(!looks_like_flag("-"));
     }
 }
@@ -0,0 +1,35 @@
+// Float functions that core doesn't provide, backed by libm for no_std builds
+pub(crate) trait F64Ext {
+    fn sqrt(self) -> f64;
+    fn round(self) -> f64;
+    fn cbrt(self) -> f64;
+    fn acos(self) -> f64;
+    fn cos(self) -> f64;
+    fn powi(self, n: i32) -> f64;
+}
+
+impl F64Ext for f64 {
+    fn sqrt(self) -> f64 {
+        libm::sqrt(self)
+    }
+
+    fn round(self) -> f64 {
+        libm::round(self)
+    }
+
+    fn cbrt(self) -> f64 {
+        libm::cbrt(self)
+    }
+
+    fn acos(self) -> f64 {
+        libm::acos(self)
+    }
+
+    fn cos(self) -> f64 {
+        libm::cos(self)
+    }
+
+    fn powi(self, n: i32) -> f64 {
+        libm::pow(self, f64::from(n))
+    }
+}
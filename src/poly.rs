@@ -0,0 +1,2789 @@
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::math::F64Ext;
+use crate::Complex;
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Range;
+use core::str::FromStr;
+use finder::{CompanionMatrix, QuadraticFormula, RootFinder};
+
+// Arithmetic between polynomials
+mod ops;
+
+// Storage for coefficients that keeps quadratics inline
+mod coefficients;
+use coefficients::Coefficients;
+
+// Parsing of polynomials written as a product of factors
+mod factored;
+
+// Plotting polynomials as SVG images
+mod svg;
+
+// Pluggable strategies for finding roots
+pub mod finder;
+
+// Roots found as the eigenvalues of the companion matrix
+mod companion;
+
+// Arithmetic in parenthesized coefficents like (3+2), for both f64 and exact coefficents
+mod arithmetic;
+use arithmetic::{evaluate_arithmetic, CoefficientError};
+
+// Rational roots from the rational root theorem
+mod rational;
+pub use rational::Rational;
+use rational::MAX_EXACT_INTEGER;
+
+// Irrational roots written exactly as simplified surds
+mod surd;
+
+// Intervals of x where a polynomial has some sign, or rises or falls
+mod intervals;
+pub use intervals::{Interval, Monotonicity, Sign};
+
+// Exact arithmetic over arbitrary-precision rationals, for coefficients too large for an f64
+#[cfg(feature = "exact")]
+pub mod exact;
+
+// Compares two roots for sorting. Real roots follow `f64::total_cmp`, and NaN roots (which the
+// quadratic equation produces for complex factors) always sort after every real root, whatever
+// their sign bit, so the resulting order is deterministic.
+pub fn compare_roots(lhs: &f64, rhs: &f64) -> Ordering {
+    match (lhs.is_nan(), rhs.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => lhs.total_cmp(rhs),
+    }
+}
+
+// Sorts roots in ascending order using `compare_roots`
+pub fn sort_roots(roots: &mut [f64]) {
+    roots.sort_by(compare_roots);
+}
+
+// Sorts complex roots by their real parts, then their imaginary parts, each compared with
+// `compare_roots`. This puts the negative imaginary part of a conjugate pair first.
+pub fn sort_complex_roots(roots: &mut [Complex]) {
+    roots.sort_by(|lhs, rhs| compare_roots(&lhs.re, &rhs.re).then(compare_roots(&lhs.im, &rhs.im)));
+}
+
+// Gets every quadratic with the leading coefficient a whose roots are both integers from lo to hi,
+// including double roots, for generating worksheets. Each pair of roots is only used once,
+// whatever its order, and the quadratics are in ascending order of their roots.
+pub fn enumerate_integer_quadratics(a: f64, root_lo: i64, root_hi: i64) -> Vec<Polynomial> {
+    if a == 0_f64 {
+        return Vec::new();
+    }
+    (root_lo..=root_hi)
+        .flat_map(|root1| {
+            (root1..=root_hi)
+                .map(move |root2| Polynomial::from_roots(a, &[root1 as f64, root2 as f64]))
+        })
+        .collect()
+}
+
+// What kind of roots a polynomial has
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RootKind {
+    // Every root is real
+    Real,
+    // At least one root is complex
+    Complex,
+    // A nonzero constant, which is never zero
+    NoRoots,
+    // The zero polynomial, where every x is a root
+    Infinite,
+}
+
+// Whether the vertex of a parabola is its lowest or highest point
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Extremum {
+    Min,
+    Max,
+}
+
+impl fmt::Display for Extremum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Extremum::Min => write!(f, "minimum"),
+            Extremum::Max => write!(f, "maximum"),
+        }
+    }
+}
+
+// Represents a polynomial of any degree, solved as the quadratic Ax^2 + Bx + C. Polynomials are
+// equal, and hash the same, when their canonical coefficients are.
+#[derive(Debug, Clone)]
+pub struct Polynomial {
+    // The coefficients indexed by degree, without any trailing zeros
+    coefficients: Coefficients,
+}
+impl Polynomial {
+    // Creates a polynomial from its coefficients indexed by degree
+    fn new(mut coefficients: Coefficients) -> Self {
+        while coefficients.last() == Some(&0_f64) {
+            coefficients.pop();
+        }
+        Self { coefficients }
+    }
+
+    // Creates a polynomial with only a constant term
+    fn constant(value: f64) -> Self {
+        Self::new(core::iter::once(value).collect())
+    }
+
+    // Creates a polynomial from its coefficients, highest degree first
+    pub fn from_coefficients(coefficients: &[f64]) -> Self {
+        Self::new(coefficients.iter().rev().cloned().collect())
+    }
+
+    // Creates the polynomial with the given leading coefficient and roots, leading·(x - r1)(x - r2)...
+    // A repeated root is listed once for each time it repeats.
+    pub fn from_roots(leading: f64, roots: &[f64]) -> Self {
+        roots
+            .iter()
+            .fold(Polynomial::constant(leading), |product, root| {
+                &product * &Polynomial::from_coefficients(&[1_f64, -root])
+            })
+    }
+
+    // Creates a polynomial from (degree, coefficient) terms in any order, adding up the
+    // coefficients of terms with the same degree, as the parser does
+    pub fn from_terms(terms: &[(u8, f64)]) -> Self {
+        let mut coefficients = Coefficients::new();
+        for &(degree, coefficient) in terms {
+            let degree = usize::from(degree);
+            if coefficients.len() <= degree {
+                coefficients.resize(degree + 1);
+            }
+            coefficients[degree] += coefficient;
+        }
+        Self::new(coefficients)
+    }
+
+    // Gets the coefficients, highest degree first, so [A, B, C] for a quadratic. This is the same
+    // order `from_coefficients` takes, and the zero polynomial has no coefficients.
+    pub fn coefficients(&self) -> Vec<f64> {
+        self.coefficients.iter().rev().cloned().collect()
+    }
+
+    // Gets the coefficients highest degree first like `coefficients`, but with any too small to
+    // display snapped to zero, as `Display` skips them, and negative zeros made positive. The
+    // leading coefficients this leaves as zero are dropped.
+    pub fn canonical_coefficients(&self) -> Vec<f64> {
+        self.coefficients
+            .iter()
+            .rev()
+            .map(|x| {
+                if x.abs() < DISPLAY_THRESHOLD {
+                    0_f64
+                } else {
+                    x + 0_f64
+                }
+            })
+            .skip_while(|x| *x == 0_f64)
+            .collect()
+    }
+
+    // Gets the coefficient of the term with the given degree
+    pub fn coefficient(&self, degree: usize) -> f64 {
+        self.coefficients.get(degree).cloned().unwrap_or(0_f64)
+    }
+
+    // Gets the (degree, coefficient) pair of each term, highest degree first, skipping terms with a
+    // zero coefficient
+    pub fn terms(&self) -> impl Iterator<Item = (usize, f64)> + '_ {
+        self.coefficients
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, coefficient)| **coefficient != 0_f64)
+            .map(|(degree, coefficient)| (degree, *coefficient))
+    }
+
+    // Checks if any coefficient is nonzero but too small to display, which is usually what's left
+    // of terms that should have cancelled out, like 0.1x + 0.2x - 0.3x
+    pub fn has_negligible_coefficients(&self) -> bool {
+        self.terms()
+            .any(|(_, coefficient)| coefficient.abs() < DISPLAY_THRESHOLD)
+    }
+
+    // Gets the quadratic coefficient A
+    fn a(&self) -> f64 {
+        self.coefficient(2)
+    }
+
+    // Gets the linear coefficient B
+    fn b(&self) -> f64 {
+        self.coefficient(1)
+    }
+
+    // Gets the constant term C
+    fn c(&self) -> f64 {
+        self.coefficient(0)
+    }
+
+    // Gets the degree of the highest term, which is 0 for the zero polynomial
+    pub fn degree(&self) -> usize {
+        self.coefficients.len().saturating_sub(1)
+    }
+
+    // Gets the degree without any leading coefficients too small to display, which are what's left
+    // of terms that should have cancelled out, like the x^2 terms of 0.1x^2 + 0.2x^2 - 0.3x^2 + x.
+    // They're only skipped when a lower coefficient isn't negligible, so a polynomial with only
+    // tiny coefficients keeps its degree.
+    pub fn effective_degree(&self) -> usize {
+        match self
+            .coefficients
+            .iter()
+            .rposition(|x| x.abs() >= DISPLAY_THRESHOLD)
+        {
+            Some(degree) => degree,
+            None => self.degree(),
+        }
+    }
+
+    // Gets the polynomial without the leading coefficients skipped by `effective_degree`
+    pub fn effective_polynomial(&self) -> Polynomial {
+        let coefficients =
+            &self.coefficients[..self.coefficients.len().min(self.effective_degree() + 1)];
+        Polynomial::new(coefficients.iter().cloned().collect())
+    }
+
+    // Gets the roots of the polynomial through the quadratic equation. Only meaningful when the
+    // degree is at most 2. The first root is the one from adding the square root of the discriminant.
+    // A linear polynomial has its single root first and NaN second. A polynomial whose leading
+    // terms almost cancelled out is solved at its effective degree, so the leftover doesn't add a
+    // huge spurious root.
+    pub fn roots(&self) -> (f64, f64) {
+        let roots = QuadraticFormula.find_roots(&self.effective_polynomial());
+        (roots[0], roots[1])
+    }
+
+    // Gets the roots after adding delta to the coefficient of the given degree, to see how
+    // sensitive the roots are to that coefficient. The polynomial itself is left unchanged.
+    pub fn roots_perturbed(&self, coeff_degree: u8, delta: f64) -> (f64, f64) {
+        let mut coefficients = self.coefficients.clone();
+        coefficients.resize(coeff_degree as usize + 1);
+        coefficients[coeff_degree as usize] += delta;
+        Polynomial::new(coefficients).roots()
+    }
+
+    // Gets what kind of roots the polynomial has at its effective degree
+    pub fn root_kind(&self) -> RootKind {
+        if self.effective_degree() < self.degree() {
+            return self.effective_polynomial().root_kind();
+        }
+        if self.coefficients.is_empty() {
+            RootKind::Infinite
+        } else if self.degree() == 0 {
+            RootKind::NoRoots
+        } else if self.all_roots().iter().all(Complex::is_real) {
+            RootKind::Real
+        } else {
+            RootKind::Complex
+        }
+    }
+
+    // Gets every root as a complex number, with an imaginary part of 0 for real roots. There is one
+    // root per effective degree, so a repeated root is listed once for each time it repeats. The roots are
+    // in the canonical order from `sort_complex_roots`, so the output doesn't depend on which
+    // branch of the square root or which eigenvalue converges first.
+    pub fn all_roots(&self) -> Vec<Complex> {
+        if self.effective_degree() < self.degree() {
+            return self.effective_polynomial().all_roots();
+        }
+        let mut roots = match self.degree() {
+            0 => Vec::new(),
+            1 => vec![Complex::real(-self.c() / self.b())],
+            2 => {
+                let (root1, root2) = self.roots();
+                if root1.is_finite() && root2.is_finite() {
+                    vec![Complex::real(root1), Complex::real(root2)]
+                } else {
+                    // Scaling keeps the discriminant from overflowing, as in the quadratic equation
+                    let scale = self.a().abs().max(self.b().abs()).max(self.c().abs());
+                    let (a, b, c) = (self.a() / scale, self.b() / scale, self.c() / scale);
+                    let re = -b / (2_f64 * a);
+                    let im = (4_f64 * a * c - b * b).sqrt() / (2_f64 * a).abs();
+                    vec![Complex::new(re, im), Complex::new(re, -im)]
+                }
+            }
+            _ => self.numeric_roots(),
+        };
+        sort_complex_roots(&mut roots);
+        roots
+    }
+
+    // Gets every root numerically as the eigenvalues of the companion matrix, which works for any
+    // degree. Real roots have an imaginary part of 0. Should the eigenvalues fail to converge, the
+    // roots are found with the Durand-Kerner method instead.
+    pub fn numeric_roots(&self) -> Vec<Complex> {
+        companion::companion_roots(self).unwrap_or_else(|| finder::durand_kerner(self))
+    }
+
+    // Gets the real roots, leaving out any complex ones. Roots above degree 2 are found numerically
+    // from the companion matrix, in ascending order. Leading terms that almost cancelled out are
+    // skipped, as in `roots`.
+    pub fn real_roots(&self) -> Vec<f64> {
+        if self.effective_degree() < self.degree() {
+            return self.effective_polynomial().real_roots();
+        }
+        let roots = if self.degree() <= 2 {
+            QuadraticFormula.find_roots(self)
+        } else {
+            CompanionMatrix.find_roots(self)
+        };
+        roots.into_iter().filter(|root| root.is_finite()).collect()
+    }
+
+    // Gets the real roots within [lo, hi], for when only roots in some domain have meaning
+    pub fn roots_in_range(&self, lo: f64, hi: f64) -> Vec<f64> {
+        self.real_roots()
+            .into_iter()
+            .filter(|root| (lo..=hi).contains(root))
+            .collect()
+    }
+
+    // Gets the vertex (x, y) of a quadratic's parabola, or None if it isn't a quadratic
+    pub fn vertex(&self) -> Option<(f64, f64)> {
+        if self.degree() != 2 {
+            return None;
+        }
+        let x = -self.b() / (2_f64 * self.a());
+        Some((x, self.evaluate(x)))
+    }
+
+    // Gets the critical points, where the derivative is zero, in ascending order. For a quadratic
+    // this is the x of its vertex, and a polynomial of degree 1 or less has none.
+    pub fn critical_points(&self) -> Vec<f64> {
+        if self.degree() < 2 {
+            return Vec::new();
+        }
+        let mut points = self.derivative().real_roots();
+        sort_roots(&mut points);
+        points.dedup();
+        points
+    }
+
+    // Gets whether a quadratic's parabola opens upward, from the sign of its leading coefficient,
+    // or None if it isn't a quadratic
+    pub fn opens_upward(&self) -> Option<bool> {
+        if self.degree() != 2 {
+            return None;
+        }
+        Some(self.a() > 0_f64)
+    }
+
+    // Gets whether a quadratic's vertex is its minimum or maximum, along with the value there. A
+    // polynomial that isn't a quadratic has no extremum.
+    pub fn extreme_value(&self) -> Option<(Extremum, f64)> {
+        let (_, y) = self.vertex()?;
+        if self.opens_upward()? {
+            Some((Extremum::Min, y))
+        } else {
+            Some((Extremum::Max, y))
+        }
+    }
+
+    // Describes the polynomial in a sentence, like "An upward-opening parabola with vertex (-2, 0)
+    // and a double real root at x = -2." Quadratics are described by the direction they open,
+    // their vertex and their roots, and other degrees more briefly. The degree is the effective one.
+    pub fn describe(&self) -> String {
+        if self.effective_degree() < self.degree() {
+            return self.effective_polynomial().describe();
+        }
+        let mut roots = self.real_roots();
+        sort_roots(&mut roots);
+        match self.degree() {
+            _ if self.root_kind() == RootKind::Infinite => {
+                "The zero polynomial, which is zero for every x.".to_owned()
+            }
+            0 => format!("A constant {}, which has no roots.", format_root(self.c())),
+            1 => format!(
+                "A line with slope {} and a root at x = {}.",
+                format_root(self.b()),
+                format_root(roots[0])
+            ),
+            2 => {
+                let (x, y) = self.vertex().unwrap();
+                let direction = if self.a() > 0_f64 {
+                    "An upward"
+                } else {
+                    "A downward"
+                };
+                let roots = match self.count_distinct_real_roots() {
+                    Some(2) => format!(
+                        "two real roots at x = {} and x = {}",
+                        format_root(roots[0]),
+                        format_root(roots[1])
+                    ),
+                    Some(1) => format!("a double real root at x = {}", format_root(x)),
+                    _ => "no real roots".to_owned(),
+                };
+                format!(
+                    "{}-opening parabola with vertex ({}, {}) and {}.",
+                    direction,
+                    format_root(x),
+                    format_root(y),
+                    roots
+                )
+            }
+            degree => format!(
+                "A degree {} polynomial with {} distinct real roots.",
+                degree,
+                self.count_distinct_real_roots().unwrap_or(0)
+            ),
+        }
+    }
+
+    // Gets the points where this polynomial and another intersect, from the roots of their
+    // difference, in ascending order of x. A point where they only touch is listed once, and
+    // polynomials that are equal everywhere have no points listed.
+    pub fn intersections(&self, other: &Polynomial) -> Vec<(f64, f64)> {
+        let mut roots = (self - other).real_roots();
+        sort_roots(&mut roots);
+        roots.dedup();
+        roots
+            .into_iter()
+            .map(|root| (root, self.evaluate(root)))
+            .collect()
+    }
+
+    // Gets each real root along with its residual, the value of the polynomial at the root, which
+    // is zero for an exact root
+    pub fn roots_with_residual(&self) -> Vec<(f64, f64)> {
+        self.real_roots()
+            .into_iter()
+            .map(|root| (root, self.evaluate(root)))
+            .collect()
+    }
+
+    // Counts the distinct real roots, or None for the zero polynomial, where every x is a root. A
+    // quadratic whose discriminant is zero to within a relative tolerance has one. Above degree 2,
+    // repeated roots are split off first with the square-free decomposition, since numerically
+    // they tend to come out as complex pairs or as roots that are only nearly equal.
+    pub fn count_distinct_real_roots(&self) -> Option<usize> {
+        match self.root_kind() {
+            RootKind::Infinite => return None,
+            RootKind::NoRoots => return Some(0),
+            _ => (),
+        }
+        if self.degree() == 1 {
+            return Some(1);
+        }
+        if self.degree() == 2 {
+            let scale = (self.b() * self.b()).max((4_f64 * self.a() * self.c()).abs());
+            let discriminant = self.discriminant();
+            return Some(if discriminant.abs() <= TOLERANCE * scale {
+                1
+            } else if discriminant > 0_f64 {
+                2
+            } else {
+                0
+            });
+        }
+        Some(
+            self.square_free_decomposition()
+                .iter()
+                .map(|(factor, _)| match factor.degree() {
+                    0..=2 => factor.count_distinct_real_roots().unwrap_or(0),
+                    _ => factor.real_roots().len(),
+                })
+                .sum(),
+        )
+    }
+
+    // Checks if the polynomial is the square of a linear factor, (px + q)^2. The discriminant only
+    // needs to be zero to within a relative tolerance.
+    pub fn is_perfect_square(&self) -> bool {
+        if self.degree() != 2 || self.a() < 0_f64 || self.c() < 0_f64 {
+            return false;
+        }
+        let scale = (self.b() * self.b()).max(4_f64 * self.a() * self.c());
+        self.discriminant().abs() <= TOLERANCE * scale
+    }
+
+    // Gets the ratio of this polynomial to another if it is a constant multiple of it, so that they
+    // have the same roots. Coefficients only need to match to within a relative tolerance.
+    pub fn is_proportional_to(&self, other: &Polynomial) -> Option<f64> {
+        if self.coefficients.is_empty()
+            || other.coefficients.is_empty()
+            || self.degree() != other.degree()
+        {
+            return None;
+        }
+        let ratio = self.coefficient(self.degree()) / other.coefficient(other.degree());
+        let scale = self
+            .coefficients
+            .iter()
+            .fold(0_f64, |acc, coefficient| acc.max(coefficient.abs()));
+        let proportional = self
+            .coefficients
+            .iter()
+            .zip(other.coefficients.iter())
+            .all(|(lhs, rhs)| (lhs - ratio * rhs).abs() <= TOLERANCE * scale);
+        if proportional {
+            Some(ratio)
+        } else {
+            None
+        }
+    }
+
+    // Checks if the polynomial has integer coefficients and factors over the rationals, which for a
+    // quadratic means the discriminant is a perfect square
+    pub fn factors_over_rationals(&self) -> bool {
+        if !self.coefficients.iter().all(|x| *x == x.round()) {
+            return false;
+        }
+        match self.degree() {
+            1 => true,
+            2 => {
+                let discriminant = self.discriminant();
+                let root = discriminant.sqrt().round();
+                discriminant >= 0_f64 && root * root == discriminant
+            }
+            _ => false,
+        }
+    }
+
+    // Gets the distance between the two real roots, or None if they are complex
+    pub fn root_separation(&self) -> Option<f64> {
+        let (root1, root2) = self.roots();
+        if root1.is_finite() && root2.is_finite() {
+            Some((root1 - root2).abs())
+        } else {
+            None
+        }
+    }
+
+    // Checks if a quadratic has two distinct real roots, from a positive discriminant, that are
+    // so close they round to the same displayed value, so they'd look like a double root
+    pub fn has_close_roots(&self) -> bool {
+        if self.degree() != 2 || self.discriminant() <= 0_f64 {
+            return false;
+        }
+        let (root1, root2) = self.roots();
+        root1 != root2 && format_root(root1) == format_root(root2)
+    }
+
+    // Evaluates the polynomial at x
+    pub fn evaluate(&self, x: f64) -> f64 {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0_f64, |acc, coefficient| acc * x + coefficient)
+    }
+
+    // Gets a closure evaluating the polynomial, for passing to code generic over functions like
+    // integrators and plotters. It owns a copy of the coefficients, so it can outlive the
+    // polynomial.
+    pub fn as_fn(&self) -> impl Fn(f64) -> f64 {
+        let polynomial = self.clone();
+        move |x| polynomial.evaluate(x)
+    }
+
+    // Evaluates the polynomial at a complex number
+    pub fn evaluate_complex(&self, z: Complex) -> Complex {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(Complex::real(0_f64), |acc, coefficient| {
+                acc * z + Complex::real(*coefficient)
+            })
+    }
+
+    // Applies a function to the coefficient of every degree up to the polynomial's degree, giving a
+    // polynomial of the results. The function gets each degree along with its coefficient.
+    pub fn map_coefficients<F: Fn(usize, f64) -> f64>(&self, f: F) -> Polynomial {
+        Polynomial::new(
+            self.coefficients
+                .iter()
+                .enumerate()
+                .map(|(degree, coefficient)| f(degree, *coefficient))
+                .collect(),
+        )
+    }
+
+    // Rounds every coefficient to the given number of decimal places, to smooth out noise like the
+    // leftovers of terms that cancelled out. Coefficients with too many digits for the rounding to
+    // change them are left alone.
+    pub fn round_coefficients(&self, decimals: u32) -> Polynomial {
+        let scale = 10_f64.powi(i32::try_from(decimals).unwrap_or(i32::MAX));
+        self.map_coefficients(|_, coefficient| {
+            let scaled = coefficient * scale;
+            if scaled.is_finite() && scaled.abs() < MAX_EXACT_INTEGER {
+                (scaled.round() / scale) + 0_f64
+            } else {
+                coefficient
+            }
+        })
+    }
+
+    // Gets the derivative of the polynomial
+    pub fn derivative(&self) -> Polynomial {
+        Polynomial::new(
+            (1..self.coefficients.len())
+                .map(|degree| degree as f64 * self.coefficient(degree))
+                .collect(),
+        )
+    }
+
+    // Estimates how sensitive the roots are to small changes in the coefficients. For each root r,
+    // changing every coefficient by a relative amount e moves the root by about
+    // e * (|A|r^2 + |B||r| + |C|) / |2Ar + B|, so this is the largest of those factors of e. The
+    // denominator is the derivative at the root, which vanishes at a repeated root, so roots that
+    // are close together have a large condition number and shouldn't be trusted to many digits.
+    // A polynomial without roots has a condition number of 0.
+    pub fn root_condition_number(&self) -> f64 {
+        let derivative = self.derivative();
+        self.all_roots()
+            .into_iter()
+            .map(|root| {
+                let magnitude = root.norm_squared().sqrt();
+                let bound = self
+                    .coefficients
+                    .iter()
+                    .rev()
+                    .fold(0_f64, |acc, coefficient| {
+                        acc * magnitude + coefficient.abs()
+                    });
+                bound / derivative.evaluate_complex(root).norm_squared().sqrt()
+            })
+            .fold(0_f64, f64::max)
+    }
+
+    // Gets the discriminant (B^2 - 4AC) of the polynomial
+    pub fn discriminant(&self) -> f64 {
+        self.b() * self.b() - 4_f64 * self.a() * self.c()
+    }
+
+    // Gets the polynomial with its coefficents reversed, whose roots are the reciprocals of this
+    // polynomial's roots. A zero constant term (a root of 0) leaves a zero leading coefficent,
+    // since the reciprocal of that root is infinite.
+    pub fn reciprocal(&self) -> Polynomial {
+        let mut coefficients = self.coefficients.clone();
+        coefficients.reverse();
+        Polynomial::new(coefficients)
+    }
+
+    // Gets the composition f(g(x)) of this polynomial f with the polynomial g
+    pub fn compose(&self, other: &Polynomial) -> Polynomial {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(Polynomial::new(Coefficients::new()), |acc, &coefficient| {
+                &(&acc * other) + &Polynomial::constant(coefficient)
+            })
+    }
+
+    // Gets the polynomial moved up by k, so its curve is f(x) + k
+    pub fn shift_vertical(&self, k: f64) -> Polynomial {
+        self + &Polynomial::constant(k)
+    }
+
+    // Gets the polynomial moved right by h, so its curve is f(x - h) and each root grows by h
+    pub fn shift_horizontal(&self, h: f64) -> Polynomial {
+        self.compose(&Polynomial::from_coefficients(&[1_f64, -h]))
+    }
+
+    // Divides this polynomial by another with long division, giving the quotient and the remainder,
+    // whose degree is less than the divisor's. Returns None when dividing by the zero polynomial.
+    pub fn checked_div_rem(&self, divisor: &Polynomial) -> Option<(Polynomial, Polynomial)> {
+        if divisor.coefficients.is_empty() {
+            return None;
+        }
+        let divisor_degree = divisor.degree();
+        if self.coefficients.len() <= divisor_degree {
+            return Some((Polynomial::new(Coefficients::new()), self.clone()));
+        }
+        let leading = divisor.coefficient(divisor_degree);
+        let mut remainder = self.coefficients.clone();
+        let mut quotient = Coefficients::zeros(self.degree() - divisor_degree + 1);
+        for degree in (0..quotient.len()).rev() {
+            // Cancel the highest remaining term, which is set to exactly zero so rounding error
+            // doesn't leave it behind
+            let coefficient = remainder[degree + divisor_degree] / leading;
+            quotient[degree] = coefficient;
+            for (i, term) in divisor.coefficients.iter().enumerate() {
+                remainder[degree + i] -= coefficient * term;
+            }
+            remainder[degree + divisor_degree] = 0_f64;
+        }
+        Some((Polynomial::new(quotient), Polynomial::new(remainder)))
+    }
+
+    // Divides this polynomial by another, like `checked_div_rem`, panicking when dividing by the
+    // zero polynomial
+    pub fn div_rem(&self, divisor: &Polynomial) -> (Polynomial, Polynomial) {
+        match self.checked_div_rem(divisor) {
+            Some(result) => result,
+            None => panic!("division by the zero polynomial"),
+        }
+    }
+
+    // Gets the greatest common divisor of two polynomials with the Euclidean algorithm, as a monic
+    // polynomial. Rounding error keeps float remainders from reaching exactly zero, so relatively
+    // tiny coefficients in each remainder are dropped. The gcd of two zero polynomials is zero.
+    pub fn gcd(&self, other: &Polynomial) -> Polynomial {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.coefficients.is_empty() {
+            let remainder = a.div_rem(&b).1;
+            let scale = a.coefficients.iter().fold(0_f64, |acc, x| acc.max(x.abs()));
+            a = b;
+            b = remainder.map_coefficients(|_, coefficient| {
+                if coefficient.abs() <= TOLERANCE * scale {
+                    0_f64
+                } else {
+                    coefficient
+                }
+            });
+        }
+        a.monic()
+    }
+
+    // Splits the polynomial into monic square-free factors with Yun's algorithm, each paired with
+    // its multiplicity, so the roots of a factor with multiplicity 2 are double roots. Factors
+    // are listed in increasing multiplicity, and the leading coefficient is left out.
+    pub fn square_free_decomposition(&self) -> Vec<(Polynomial, u32)> {
+        let mut factors = Vec::new();
+        if self.degree() == 0 {
+            return factors;
+        }
+        let derivative = self.derivative();
+        let common = self.gcd(&derivative);
+        let mut b = self.div_rem(&common).0;
+        let mut d = &derivative.div_rem(&common).0 - &b.derivative();
+        let mut multiplicity = 1;
+        while b.degree() > 0 {
+            let factor = b.gcd(&d);
+            b = b.div_rem(&factor).0;
+            d = &d.div_rem(&factor).0 - &b.derivative();
+            if factor.degree() > 0 {
+                factors.push((factor, multiplicity));
+            }
+            multiplicity += 1;
+        }
+        factors
+    }
+
+    // Divides every coefficient by the leading one, leaving the zero polynomial alone
+    fn monic(&self) -> Polynomial {
+        match self.coefficients.last() {
+            Some(&leading) => self.map_coefficients(|_, coefficient| coefficient / leading),
+            None => self.clone(),
+        }
+    }
+
+    // Checks if the polynomial is a quadratic like 3x^2 with no linear or constant term, so its
+    // only root is a double root at 0
+    pub fn has_double_root_at_zero(&self) -> bool {
+        self.degree() == 2 && self.b() == 0_f64 && self.c() == 0_f64
+    }
+
+    // Gets the polynomial written as the product of its (real) factors, or None if it has
+    // complex roots or isn't quadratic. When the constant term is zero, x is factored out, and
+    // when the linear term is zero too, what's left is Ax·x.
+    pub fn factored_form(&self) -> Option<String> {
+        let (root1, root2) = self.roots();
+        if self.degree() != 2 || !root1.is_finite() || !root2.is_finite() {
+            return None;
+        }
+        let leading = if self.a() == 1_f64 {
+            String::new()
+        } else if self.a() == -1_f64 {
+            "-".to_owned()
+        } else {
+            format_number(self.a())
+        };
+
+        // Both roots are exactly 0, so the polynomial is only its quadratic term
+        if self.has_double_root_at_zero() {
+            return Some(format!("{}x·x", leading));
+        }
+
+        // One root is exactly 0, so x is a common factor of each term
+        if self.c() == 0_f64 {
+            return Some(format!(
+                "{}x({})",
+                leading,
+                linear_factor(-self.b() / self.a())
+            ));
+        }
+
+        if root1 == root2 {
+            return Some(format!("{}({})^2", leading, linear_factor(root1)));
+        }
+        let mut roots = [root1, root2];
+        sort_roots(&mut roots);
+        Some(format!(
+            "{}({})({})",
+            leading,
+            linear_factor(roots[0]),
+            linear_factor(roots[1])
+        ))
+    }
+
+    // Gets the linear factors px + q of a quadratic as (p, q) pairs, whose product is the
+    // polynomial, or None if it has complex roots or isn't quadratic. The factors are in
+    // descending order of their roots, with the leading coefficient multiplied into the first, so
+    // x^2 - 1 gives (x - 1)(x + 1).
+    pub fn binomial_factors(&self) -> Option<Vec<(f64, f64)>> {
+        let (root1, root2) = self.roots();
+        if self.degree() != 2 || !root1.is_finite() || !root2.is_finite() {
+            return None;
+        }
+        let mut roots = [root1, root2];
+        sort_roots(&mut roots);
+        Some(vec![
+            (self.a(), -self.a() * roots[1] + 0_f64),
+            (1_f64, -roots[0] + 0_f64),
+        ])
+    }
+
+    // Renders the quadratic equation with the coefficients substituted in, as LaTeX.
+    // A negative discriminant is written as an imaginary square root, e.g. \sqrt{64}i. A linear
+    // polynomial is solved as the fraction -C/B, and a constant one has no solution unless it's
+    // zero, where every x is one.
+    pub fn to_latex(&self) -> String {
+        let polynomial = self.effective_polynomial();
+        if polynomial.a() == 0_f64 {
+            return if polynomial.b() != 0_f64 {
+                format!(
+                    "x = \\frac{{{}}}{{{}}}",
+                    format_number(-polynomial.c()),
+                    format_number(polynomial.b())
+                )
+            } else if polynomial.c() == 0_f64 {
+                "x \\in \\mathbb{R}".to_owned()
+            } else {
+                "\\text{no solution}".to_owned()
+            };
+        }
+        let discriminant = self.discriminant();
+        let radical = if discriminant < 0_f64 {
+            format!("\\sqrt{{{}}}i", format_number(-discriminant))
+        } else {
+            format!("\\sqrt{{{}}}", format_number(discriminant))
+        };
+        format!(
+            "x = \\frac{{{} \\pm {}}}{{{}}}",
+            format_number(-self.b()),
+            radical,
+            format_number(2_f64 * self.a())
+        )
+    }
+}
+
+// The relative tolerance used when comparing values that have gone through floating-point arithmetic
+const TOLERANCE: f64 = 1e-9;
+
+// Coefficients smaller than this in magnitude are treated as zero when displaying a polynomial, so
+// that floating-point leftovers from cancelling terms aren't shown
+pub(crate) const DISPLAY_THRESHOLD: f64 = 1e-12;
+
+// Writes the polynomial in its canonical form, e.g. "x^2 - 4x + 4"
+impl fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut first = true;
+        for (degree, &coefficient) in self.coefficients.iter().enumerate().rev() {
+            if coefficient.abs() < DISPLAY_THRESHOLD {
+                continue;
+            }
+            let sign = match (first, coefficient < 0_f64) {
+                (true, false) => "",
+                (true, true) => "-",
+                (false, false) => " + ",
+                (false, true) => " - ",
+            };
+            let magnitude = if coefficient.abs() == 1_f64 && degree > 0 {
+                String::new()
+            } else {
+                format_number(coefficient.abs())
+            };
+            let variable = match degree {
+                0 => String::new(),
+                1 => "x".to_owned(),
+                _ => format!("x^{}", degree),
+            };
+            write!(f, "{}{}{}", sign, magnitude, variable)?;
+            first = false;
+        }
+        if first {
+            write!(f, "0")?;
+        }
+        Ok(())
+    }
+}
+
+impl PartialEq for Polynomial {
+    fn eq(&self, other: &Polynomial) -> bool {
+        let (lhs, rhs) = (
+            self.canonical_coefficients(),
+            other.canonical_coefficients(),
+        );
+        lhs.len() == rhs.len()
+            && lhs
+                .iter()
+                .zip(rhs.iter())
+                .all(|(lhs, rhs)| lhs.to_bits() == rhs.to_bits())
+    }
+}
+
+// Comparing the bits of the canonical coefficients makes equality reflexive, even for NaN
+impl Eq for Polynomial {}
+
+impl Hash for Polynomial {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical_coefficients()
+            .iter()
+            .map(|x| x.to_bits())
+            .collect::<Vec<u64>>()
+            .hash(state);
+    }
+}
+
+impl Polynomial {
+    // Formats the polynomial like `Display`, but with -1 factored out when the leading coefficient
+    // is negative, so -x^2 + 4x - 4 is written -(x^2 - 4x + 4)
+    pub fn to_positive_leading_string(&self) -> String {
+        match self.coefficients.last() {
+            Some(leading) if *leading < 0_f64 => format!("-({})", -self),
+            _ => format!("{}", self),
+        }
+    }
+}
+
+// Formats a number without a trailing ".0", and never as negative zero
+fn format_number(value: f64) -> String {
+    format!("{}", value + 0_f64)
+}
+// Formats a root rounded to four decimal places, without trailing zeros
+pub fn format_root(value: f64) -> String {
+    let rounded = format!("{:.4}", value);
+    let trimmed = rounded.trim_end_matches('0').trim_end_matches('.');
+    if trimmed == "-0" {
+        "0".to_owned()
+    } else {
+        trimmed.to_owned()
+    }
+}
+
+// Formats the linear factor (x - root). A positive root is subtracted and a negative one is added,
+// so there's never a "+ -3". A root that rounds to 0 leaves just x.
+fn linear_factor(root: f64) -> String {
+    let magnitude = format_root(root.abs());
+    if magnitude == "0" {
+        "x".to_owned()
+    } else if root > 0_f64 {
+        format!("x - {}", magnitude)
+    } else {
+        format!("x + {}", magnitude)
+    }
+}
+
+// Represents an inner expression of the form Cx^D
+struct Subpolynomial {
+    coefficent: f64,
+    degree: u8,
+}
+
+// How a term was split into its coefficent and degree strings, recording which parts were
+// filled in. This is what the parser works from, so it helps to explain surprising results.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TermParse {
+    pub coefficient: String,
+    pub degree: String,
+    // The coefficent was missing or only a sign, so a 1 was filled in
+    pub coefficient_defaulted: bool,
+    // There was no caret, so the degree was inferred from whether the term has an x
+    pub degree_inferred: bool,
+    // The degree came from the digits right after the x, with implicit exponents
+    pub implicit_exponent: bool,
+}
+
+// Splits a single term like "-3x^2" the way the parser does, for debugging
+pub fn parse_term_debug(term: &str, options: &ParseOptions) -> TermParse {
+    split_subpolynomial(term, options)
+}
+
+// The terms of one degree in a polynomial string, for seeing how they were added together
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contribution {
+    pub degree: u8,
+    // Each term as it was written, along with the coefficent it added. Terms on the right of an
+    // equals sign are subtracted, so their coefficents are negated.
+    pub terms: Vec<(String, f64)>,
+    // The sum of the coefficents, which is the coefficient of this degree in the polynomial
+    pub total: f64,
+}
+
+// Parses a polynomial string, listing the terms that were added together for each degree. The
+// degrees are in the order they first appear and the terms are in the order they were written.
+pub fn term_contributions(
+    value: &str,
+    options: &ParseOptions,
+) -> Result<Vec<Contribution>, ParseError> {
+    let mut contributions: Vec<Contribution> = Vec::new();
+    for (side, (expression, offset)) in split_equation(value)?.into_iter().enumerate() {
+        for (subpoly, span) in split_polynomial(expression, options) {
+            let parsed =
+                Subpolynomial::parse(&subpoly, span.start + offset..span.end + offset, options)?;
+            let coefficient = if side == 1 {
+                -parsed.coefficent
+            } else {
+                parsed.coefficent
+            };
+            let index = match contributions.iter().position(|x| x.degree == parsed.degree) {
+                Some(index) => index,
+                None => {
+                    contributions.push(Contribution {
+                        degree: parsed.degree,
+                        terms: Vec::new(),
+                        total: 0_f64,
+                    });
+                    contributions.len() - 1
+                }
+            };
+            contributions[index].terms.push((subpoly, coefficient));
+            contributions[index].total += coefficient;
+        }
+    }
+    Ok(contributions)
+}
+
+// Splits a subpolynomial string into its coefficent and degree strings
+fn split_subpolynomial(subpoly: &str, options: &ParseOptions) -> TermParse {
+    // Find the index of any 'x' character or the end of the expression. If not found, assume a 0th degree.
+    let coefficent_end_index = subpoly.find('x').unwrap_or(subpoly.len());
+
+    // Find the index of any '^' character plus 1. A caret with nothing after it gives an empty
+    // degree, which fails to parse.
+    let degree_start_index = subpoly.find('^').map(|i| i + 1);
+
+    // Find the coefficent string
+    let mut coefficent = subpoly[0..coefficent_end_index].to_owned();
+
+    // Try to take the degree string. With implicit exponents, digits right after the x are the
+    // degree too.
+    let mut implicit_exponent = false;
+    let degree = degree_start_index.map(|i| &subpoly[i..]).or_else(|| {
+        let exponent = subpoly.get(coefficent_end_index + 1..).unwrap_or("");
+        implicit_exponent = options.implicit_exponent && !exponent.is_empty();
+        if implicit_exponent {
+            Some(exponent)
+        } else {
+            None
+        }
+    });
+
+    // If the coefficent isn't found, or is only a sign, then add a one so it parses correctly.
+    let coefficient_defaulted = coefficent.is_empty() || coefficent == "-" || coefficent == "+";
+    if coefficient_defaulted {
+        coefficent += "1";
+    }
+
+    // If the degree isn't found, check with the coefficent to determine what our degree should be.
+    let degree_inferred = degree.is_none();
+    let degree = degree.unwrap_or(if coefficent_end_index == subpoly.len() {
+        "0"
+    } else {
+        "1"
+    });
+
+    TermParse {
+        coefficient: coefficent,
+        degree: degree.to_owned(),
+        coefficient_defaulted,
+        degree_inferred,
+        implicit_exponent,
+    }
+}
+
+impl Subpolynomial {
+    // Handles turning a string into a Subpolynomial, given the span the string covers in the polynomial
+    fn parse(
+        subpoly: &str,
+        span: Range<usize>,
+        options: &ParseOptions,
+    ) -> Result<Self, ParseError> {
+        let TermParse {
+            coefficient: coefficent,
+            degree,
+            ..
+        } = split_subpolynomial(subpoly, options);
+
+        // Parse the coefficent, which must be finite, since an overflowing one like 1e400 would
+        // silently become infinity
+        let coefficent = parse_coefficient(&coefficent)
+            .map_err(|error| error.into_parse_error(subpoly, span.clone()))?;
+        if !coefficent.is_finite() {
+            return Err(ParseError::CoefficientOverflow {
+                term: subpoly.to_owned(),
+                span,
+            });
+        }
+
+        // Parse and return the subpoly
+        Ok(Self {
+            coefficent,
+            degree: degree.parse().map_err(|_| ParseError::InvalidDegree {
+                term: subpoly.to_owned(),
+                span,
+            })?,
+        })
+    }
+}
+
+// Parses a coefficent, which is either a number, the square root of one written as sqrt(2) or
+// √2, or arithmetic in parentheses like (3+2). A square root can't be multiplied by another number
+// or nested.
+fn parse_coefficient(coefficient: &str) -> Result<f64, CoefficientError> {
+    let (sign, magnitude) = match coefficient.strip_prefix('-') {
+        Some(magnitude) => (-1_f64, magnitude),
+        None => (1_f64, coefficient.trim_start_matches('+')),
+    };
+    if magnitude.starts_with('(') {
+        return evaluate_arithmetic(magnitude).map(|value: f64| sign * value);
+    }
+    let radicand = magnitude
+        .strip_prefix("sqrt(")
+        .and_then(|radicand| radicand.strip_suffix(')'))
+        .or_else(|| magnitude.strip_prefix('√'));
+    match radicand {
+        Some(radicand) => {
+            let radicand: f64 = radicand.parse().map_err(|_| CoefficientError::Invalid)?;
+            if radicand < 0_f64 {
+                return Err(CoefficientError::Invalid);
+            }
+            Ok(sign * radicand.sqrt())
+        }
+        None => coefficient.parse().map_err(|_| CoefficientError::Invalid),
+    }
+}
+
+// Splits a polynomial string into its signed subpolynomial strings, along with the span each one
+// covers in the polynomial string. Commas are thousands separators and are dropped, unless they
+// are decimal commas.
+fn split_polynomial(polynomial: &str, options: &ParseOptions) -> Vec<(String, Range<usize>)> {
+    // Strip any whitespace, keeping the index each character came from
+    let polynomial: Vec<(usize, char)> = polynomial
+        .char_indices()
+        .filter(|(_, c)| *c != ' ' && *c != '\t')
+        .collect();
+
+    // Contains all the subpoly strings
+    let mut subpoly_vector: Vec<(String, Range<usize>)> = Vec::new();
+
+    // A buffer string used to build up a subpoly, the span it covers, and the index of its first
+    // character among the characters left after stripping whitespace
+    let mut subpoly_buffer = String::new();
+    let mut subpoly_span = 0..0;
+    let mut subpoly_start = 0_usize;
+
+    // How deep inside the parentheses of an arithmetic coefficent the current character is
+    let mut depth = 0_usize;
+    for i in 0..=polynomial.len() {
+        // Get the character at an index
+        let c = polynomial.get(i).map(|x| x.1);
+        // If the character is a sign, or it is at the end of the string...
+        if (c.is_none() || ((c == Some('+') || c == Some('-')) && depth == 0))
+            && !subpoly_buffer.is_empty()
+        {
+            // Try and find the character before the subpoly, to get the sign of the coefficent
+            let index: Option<usize> = subpoly_start.checked_sub(1);
+            let sign = index.map(|i| polynomial[i]);
+
+            // Add the sign character to the subpoly string
+            if let Some((sign_index, sign_char)) = sign {
+                if sign_char == '+' || sign_char == '-' {
+                    subpoly_buffer.insert(0, sign_char);
+                    subpoly_span.start = sign_index;
+                }
+            }
+
+            // A run of signs before the first term folds into its net sign, so --x^2 is x^2
+            if subpoly_vector.is_empty() {
+                let run = polynomial[..subpoly_start]
+                    .iter()
+                    .rev()
+                    .take_while(|(_, c)| *c == '+' || *c == '-');
+                let mut negative = None;
+                for &(sign_index, sign_char) in run {
+                    negative = Some(negative.unwrap_or(false) ^ (sign_char == '-'));
+                    subpoly_span.start = sign_index;
+                }
+                if let Some(negative) = negative {
+                    subpoly_buffer.replace_range(..1, if negative { "-" } else { "+" });
+                }
+            }
+
+            // Add the subpoly to the vec
+            subpoly_vector.push((subpoly_buffer.clone(), subpoly_span.clone()));
+            subpoly_buffer.clear();
+        }
+
+        if let Some(&(index, c)) = polynomial.get(i) {
+            // An uppercase X is the same variable. Only the X is folded, so an exponent like 1E3
+            // still reads as a number.
+            let c = match c {
+                ',' if options.decimal_comma => '.',
+                'X' => 'x',
+                c => c,
+            };
+            // The parentheses of a square root are kept so its argument can be found
+            let square_root = (c == '(' && subpoly_buffer.ends_with("sqrt"))
+                || (c == ')' && subpoly_buffer.contains("sqrt(") && !subpoly_buffer.ends_with(')'))
+                || c == '√';
+            // A term starting with a parenthesis has an arithmetic coefficent like (3+2), which is
+            // kept whole, signs and all, until its parentheses close
+            let arithmetic = depth > 0 || (c == '(' && subpoly_buffer.is_empty());
+            if arithmetic {
+                match c {
+                    '(' => depth += 1,
+                    ')' => depth -= 1,
+                    _ => (),
+                }
+            }
+            if c.is_alphanumeric()
+                || c == '^'
+                || c == '.'
+                || square_root
+                || (arithmetic && "+-*/()".contains(c))
+            {
+                if subpoly_buffer.is_empty() {
+                    subpoly_span.start = index;
+                    subpoly_start = i;
+                }
+                subpoly_buffer.push(c);
+                subpoly_span.end = index + c.len_utf8();
+            }
+        }
+    }
+    subpoly_vector
+}
+
+// An error from parsing a polynomial, with the span of the offending term in the input
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    // A term's coefficent isn't a valid number
+    InvalidCoefficient { term: String, span: Range<usize> },
+    // A term's coefficent is too large to represent, or isn't finite
+    CoefficientOverflow { term: String, span: Range<usize> },
+    // A term's degree isn't a valid whole number
+    InvalidDegree { term: String, span: Range<usize> },
+    // An equals sign after the first one
+    UnexpectedEquals { span: Range<usize> },
+    // A parenthesis without a partner, or nested inside another pair
+    UnbalancedParenthesis { span: Range<usize> },
+    // A term above x^2 where only quadratics are supported, like in exact mode
+    UnsupportedDegree { term: String, span: Range<usize> },
+    // The arithmetic in a term's coefficent divides by zero
+    DivisionByZero { term: String, span: Range<usize> },
+}
+
+impl ParseError {
+    // Gets the span of the offending term in the input
+    pub fn span(&self) -> Range<usize> {
+        match self {
+            ParseError::InvalidCoefficient { span, .. } => span.clone(),
+            ParseError::CoefficientOverflow { span, .. } => span.clone(),
+            ParseError::InvalidDegree { span, .. } => span.clone(),
+            ParseError::UnexpectedEquals { span } => span.clone(),
+            ParseError::UnbalancedParenthesis { span } => span.clone(),
+            ParseError::UnsupportedDegree { span, .. } => span.clone(),
+            ParseError::DivisionByZero { span, .. } => span.clone(),
+        }
+    }
+
+    // Gets a hint on how to fix the error
+    pub fn help(&self) -> &'static str {
+        match self {
+            ParseError::InvalidCoefficient { .. } => {
+                "coefficents must be numbers like 4, -2 or 0.5"
+            }
+            ParseError::CoefficientOverflow { .. } => {
+                "coefficents must be smaller than about 1.8e308"
+            }
+            ParseError::InvalidDegree { .. } => "degrees must be whole numbers like x^2",
+            ParseError::UnexpectedEquals { .. } => "an equation can only have one equals sign",
+            ParseError::UnbalancedParenthesis { .. } => {
+                "each factor must be wrapped in a single pair of parentheses"
+            }
+            ParseError::UnsupportedDegree { .. } => "exact mode only supports quadratics",
+            ParseError::DivisionByZero { .. } => "arithmetic in a coefficent can't divide by zero",
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::InvalidCoefficient { term, span } => write!(
+                f,
+                "Invalid coefficent in term \"{}\" at position {}.",
+                term, span.start
+            ),
+            ParseError::CoefficientOverflow { term, span } => write!(
+                f,
+                "Coefficent in term \"{}\" at position {} is too large.",
+                term, span.start
+            ),
+            ParseError::InvalidDegree { term, span } => write!(
+                f,
+                "Invalid degree in term \"{}\" at position {}.",
+                term, span.start
+            ),
+            ParseError::UnexpectedEquals { span } => {
+                write!(f, "Unexpected equals sign at position {}.", span.start)
+            }
+            ParseError::UnbalancedParenthesis { span } => {
+                write!(f, "Unbalanced parenthesis at position {}.", span.start)
+            }
+            ParseError::UnsupportedDegree { term, span } => write!(
+                f,
+                "Unsupported degree in term \"{}\" at position {}.",
+                term, span.start
+            ),
+            ParseError::DivisionByZero { term, span } => write!(
+                f,
+                "Division by zero in term \"{}\" at position {}.",
+                term, span.start
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+// Rich diagnostics highlighting the offending term in the input
+#[cfg(feature = "miette")]
+impl miette::Diagnostic for ParseError {
+    fn help<'a>(&'a self) -> Option<alloc::boxed::Box<dyn fmt::Display + 'a>> {
+        Some(alloc::boxed::Box::new(ParseError::help(self)))
+    }
+
+    fn labels(&self) -> Option<alloc::boxed::Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+        let label = match self {
+            ParseError::InvalidCoefficient { .. } => "invalid coefficent",
+            ParseError::CoefficientOverflow { .. } => "coefficent too large",
+            ParseError::InvalidDegree { .. } => "invalid degree",
+            ParseError::UnexpectedEquals { .. } => "second equals sign",
+            ParseError::UnbalancedParenthesis { .. } => "unbalanced parenthesis",
+            ParseError::UnsupportedDegree { .. } => "degree above 2",
+            ParseError::DivisionByZero { .. } => "division by zero",
+        };
+        let span = self.span();
+        Some(alloc::boxed::Box::new(core::iter::once(
+            miette::LabeledSpan::new(Some(label.to_owned()), span.start, span.len()),
+        )))
+    }
+}
+
+// Gets the length of a leading "f(x) =" or "y =" naming the polynomial, as textbooks often write
+// it, or 0 if there isn't one. Any letter can name the function.
+pub fn function_prefix_length(value: &str) -> usize {
+    let name = value.trim_start();
+    let rest = if let Some(rest) = name.strip_prefix('y') {
+        rest
+    } else {
+        let mut chars = name.chars();
+        match (chars.next(), chars.as_str().get(..3)) {
+            (Some(c), Some("(x)")) | (Some(c), Some("(X)")) if c.is_ascii_alphabetic() => {
+                &chars.as_str()[3..]
+            }
+            _ => return 0,
+        }
+    };
+    match rest.trim_start().strip_prefix('=') {
+        Some(polynomial) => value.len() - polynomial.len(),
+        None => 0,
+    }
+}
+
+// Strips a semicolon or period ending the polynomial, as when it's copied from a sentence, along
+// with any whitespace around it. Only one of each is stripped, so "x^2 - 1.;" loses both but
+// "x^2 - 1.." keeps its last number as "1.".
+pub fn strip_trailing_punctuation(value: &str) -> &str {
+    let value = value.trim_end();
+    let value = value.strip_suffix(';').unwrap_or(value).trim_end();
+    value.strip_suffix('.').unwrap_or(value).trim_end()
+}
+
+// Splits an equation into the expressions on either side of its equals sign, along with the index
+// each one starts at. An expression without an equals sign is equal to zero, and a leading
+// "f(x) =" and trailing punctuation are skipped.
+fn split_equation(value: &str) -> Result<Vec<(&str, usize)>, ParseError> {
+    let value = strip_trailing_punctuation(value);
+    let start = function_prefix_length(value);
+    let value = &value[start..];
+    let mut sides = vec![(value, start)];
+    if let Some(i) = value.find('=') {
+        if let Some(j) = value[i + 1..].find('=') {
+            let position = start + i + 1 + j;
+            return Err(ParseError::UnexpectedEquals {
+                span: position..position + 1,
+            });
+        }
+        sides = vec![(&value[..i], start), (&value[i + 1..], start + i + 1)];
+    }
+    Ok(sides)
+}
+
+// Parses one side of an equation, where the expression starts at the given index of the input
+fn parse_expression(
+    value: &str,
+    offset: usize,
+    options: &ParseOptions,
+) -> Result<Polynomial, ParseError> {
+    // Contains all the parsed subpolys as (degree, coefficent) terms
+    let terms = split_polynomial(value, options)
+        .into_iter()
+        .map(|(subpoly, span)| {
+            Subpolynomial::parse(&subpoly, span.start + offset..span.end + offset, options)
+                .map(|subpoly| (subpoly.degree, subpoly.coefficent))
+        })
+        .collect::<Result<Vec<(u8, f64)>, ParseError>>()?;
+
+    // Add up the coefficents of the subpolys by degree
+    Ok(Polynomial::from_terms(&terms))
+}
+
+// Opt-in changes to how polynomial strings are read
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ParseOptions {
+    // Reads digits right after the x as its degree, so x2 means x^2
+    pub implicit_exponent: bool,
+    // Reads commas as decimal points, so 3,5 means 3.5, instead of dropping them as thousands
+    // separators
+    pub decimal_comma: bool,
+}
+
+impl Polynomial {
+    // Parses a polynomial, or an equation which is rearranged so that it equals zero, using the
+    // given options
+    pub fn parse_with(value: &str, options: &ParseOptions) -> Result<Polynomial, ParseError> {
+        let sides = split_equation(value)?;
+        let lhs = parse_expression(sides[0].0, sides[0].1, options)?;
+        match sides.get(1) {
+            Some(&(rhs, offset)) => Ok(&lhs - &parse_expression(rhs, offset, options)?),
+            None => Ok(lhs),
+        }
+    }
+}
+
+impl FromStr for Polynomial {
+    type Err = ParseError;
+
+    // Parses a polynomial, or an equation which is rearranged so that it equals zero
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Polynomial::parse_with(value, &ParseOptions::default())
+    }
+}
+
+impl<S> From<S> for Polynomial
+where
+    S: Into<String>,
+{
+    // Parses a polynomial, panicking if it is malformed. Use `str::parse` to handle the error.
+    fn from(value: S) -> Self {
+        match value.into().parse() {
+            Ok(polynomial) => polynomial,
+            Err(error) => panic!("{}", error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Tests a basic polynomial
+    #[test]
+    fn basic_polynomial() {
+        crate::assert_roots!("x^2 + 4x + 4", [-2.0, -2.0]);
+    }
+    // Tests a polynomial who's roots are complex
+    #[test]
+    fn complex_polynomial() {
+        crate::assert_roots!("5x^2 + 4x + 4", []);
+    }
+
+    // Tests the conversion of string to polynomial
+    #[test]
+    fn format_polynomial() {
+        let string =
+            "x^2 + -0.5x + -2.5x + 2.5x + 0.5x + 4x + 8x - 4x -+-4x + 4 + 12 --+-8         -4";
+        crate::assert_roots!(string, [-2.0, -2.0]);
+    }
+
+    // Tests listing the terms that were added together for each degree of the messy polynomial
+    #[test]
+    fn messy_term_contributions() {
+        let string =
+            "x^2 + -0.5x + -2.5x + 2.5x + 0.5x + 4x + 8x - 4x -+-4x + 4 + 12 --+-8         -4";
+        let contributions = term_contributions(string, &ParseOptions::default()).unwrap();
+        let degrees: Vec<u8> = contributions.iter().map(|x| x.degree).collect();
+        assert_eq!(degrees, vec![2, 1, 0]);
+
+        let linear = &contributions[1];
+        let terms: Vec<&str> = linear.terms.iter().map(|(term, _)| term.as_str()).collect();
+        assert_eq!(
+            terms,
+            vec!["-0.5x", "-2.5x", "+2.5x", "+0.5x", "+4x", "+8x", "-4x", "-4x"]
+        );
+        let coefficients: Vec<f64> = linear.terms.iter().map(|(_, x)| *x).collect();
+        assert_eq!(
+            coefficients,
+            vec![-0.5_f64, -2.5_f64, 2.5_f64, 0.5_f64, 4_f64, 8_f64, -4_f64, -4_f64]
+        );
+        assert_eq!(linear.total, 4_f64);
+        assert_eq!(linear.total, Polynomial::from(string).coefficient(1));
+        assert_eq!(contributions[2].total, 4_f64);
+
+        let equation = term_contributions("x = 3 - x", &ParseOptions::default()).unwrap();
+        assert_eq!(
+            equation[0].terms,
+            vec![("x".to_owned(), 1_f64), ("-x".to_owned(), 1_f64)]
+        );
+        assert_eq!(equation[1].total, -3_f64);
+    }
+
+    // Tests exact roots where f64 coefficents lose precision
+    #[cfg(feature = "exact")]
+    #[test]
+    fn exact_polynomial() {
+        use super::exact::ExactPolynomial;
+        use num_rational::BigRational;
+
+        let string = "x^2 - 100000000000000002x + 100000000000000001";
+        let expected: BigRational = "100000000000000001".parse().unwrap();
+
+        let roots = Polynomial::from(string).roots();
+        assert_ne!(roots.0 as u64, 100000000000000001_u64);
+
+        let exact = ExactPolynomial::parse(string, &ParseOptions::default()).unwrap();
+        assert_eq!(
+            exact.roots().unwrap(),
+            (expected, BigRational::from_integer(1.into()))
+        );
+
+        // Linear and constant polynomials have no quadratic roots, and square roots aren't exact
+        for string in &["2x - 4", "5", "x - x"] {
+            let exact = ExactPolynomial::parse(string, &ParseOptions::default()).unwrap();
+            assert_eq!(exact.roots(), None);
+        }
+        assert!(ExactPolynomial::parse("x^2 - sqrt(2)", &ParseOptions::default()).is_err());
+        assert_eq!(
+            ExactPolynomial::parse("x^3 - 1", &ParseOptions::default()).err(),
+            Some(ParseError::UnsupportedDegree {
+                term: "x^3".into(),
+                span: 0..3
+            })
+        );
+
+        // Arithmetic coefficents are evaluated exactly
+        let exact = ExactPolynomial::parse("(3+2)x^2 - (1/3)x", &ParseOptions::default()).unwrap();
+        let roots = (
+            BigRational::new(1.into(), 15.into()),
+            BigRational::from_integer(0.into()),
+        );
+        assert_eq!(exact.roots().unwrap(), roots);
+        assert!(matches!(
+            ExactPolynomial::parse("(1/0)x^2 - 1", &ParseOptions::default()),
+            Err(ParseError::DivisionByZero { .. })
+        ));
+
+        // Coefficents written with an exponent are read exactly like the f64 parser reads them
+        let exact = ExactPolynomial::parse("1e3x^2 - 2.5E2", &ParseOptions::default()).unwrap();
+        assert_eq!(
+            exact.discriminant(),
+            BigRational::from_integer(1_000_000.into())
+        );
+
+        // Implicit exponents are read when they're enabled
+        let options = ParseOptions {
+            implicit_exponent: true,
+            ..ParseOptions::default()
+        };
+        let exact = ExactPolynomial::parse("x2 - 1", &options).unwrap();
+        assert_eq!(exact.discriminant(), BigRational::from_integer(4.into()));
+    }
+
+    // Tests that the reciprocal polynomial has the reciprocal roots
+    #[test]
+    fn reciprocal_polynomial() {
+        let poly = Polynomial::from("2x^2 + 3x + 1");
+        let reciprocal = poly.reciprocal();
+        assert_eq!(reciprocal, Polynomial::from("x^2 + 3x + 2"));
+
+        let roots = poly.roots();
+        let mut expected = [1_f64 / roots.0, 1_f64 / roots.1];
+        let reciprocal_roots = reciprocal.roots();
+        let mut actual = [reciprocal_roots.0, reciprocal_roots.1];
+        sort_roots(&mut expected);
+        sort_roots(&mut actual);
+        assert_eq!(actual, expected);
+
+        // The root of 0 is lost to infinity, leaving the reciprocal of -4
+        let reciprocal = Polynomial::from("x^2 + 4x").reciprocal();
+        assert_eq!(reciprocal, Polynomial::from("4x + 1"));
+    }
+
+    // Tests that a parse error carries the span of the offending term
+    #[test]
+    fn parse_error_span() {
+        let error = "x^2 + 1.2.3x + 4".parse::<Polynomial>().unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::InvalidCoefficient {
+                term: "+1.2.3x".to_owned(),
+                span: 4..12,
+            }
+        );
+    }
+
+    // Tests that the diagnostic labels the span of the offending term
+    #[cfg(feature = "miette")]
+    #[test]
+    fn parse_error_diagnostic() {
+        use miette::Diagnostic;
+
+        let error = "x^2 + x^2.5".parse::<Polynomial>().unwrap_err();
+        let labels: Vec<miette::LabeledSpan> = error.labels().unwrap().collect();
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].offset(), 4);
+        assert_eq!(labels[0].len(), 7);
+        assert_eq!(labels[0].label(), Some("invalid degree"));
+    }
+
+    // Tests factoring out x when the constant term is zero
+    #[test]
+    fn factored_form_common_x() {
+        let poly = Polynomial::from("x^2 + 4x");
+        assert_eq!(poly.factored_form(), Some("x(x + 4)".to_owned()));
+
+        let poly = Polynomial::from("2x^2 + 4x");
+        assert_eq!(poly.factored_form(), Some("2x(x + 2)".to_owned()));
+    }
+
+    // Tests counting the distinct real roots
+    #[test]
+    fn count_distinct_real_roots() {
+        assert_eq!(
+            Polynomial::from("x^2 + 1").count_distinct_real_roots(),
+            Some(0)
+        );
+        assert_eq!(
+            Polynomial::from("x^2 + 4x + 4").count_distinct_real_roots(),
+            Some(1)
+        );
+        assert_eq!(
+            Polynomial::from("x^2 - 1").count_distinct_real_roots(),
+            Some(2)
+        );
+        assert_eq!(
+            Polynomial::from("x^2 - 0.2x + 0.01").count_distinct_real_roots(),
+            Some(1)
+        );
+        assert_eq!(
+            Polynomial::from("2x + 1").count_distinct_real_roots(),
+            Some(1)
+        );
+        assert_eq!(Polynomial::from("5").count_distinct_real_roots(), Some(0));
+        assert_eq!(Polynomial::from("x - x").count_distinct_real_roots(), None);
+        let poly = Polynomial::from_factored("(x - 1)^2(x + 2)").unwrap();
+        assert_eq!(poly.count_distinct_real_roots(), Some(2));
+    }
+
+    // Tests finding where the derivative is zero
+    #[test]
+    fn critical_points() {
+        assert_eq!(
+            Polynomial::from("x^2 + 4x + 4").critical_points(),
+            vec![-2_f64]
+        );
+        let points = Polynomial::from("x^3 - 3x").critical_points();
+        assert_eq!(points.len(), 2);
+        assert!((points[0] + 1_f64).abs() < 1e-9_f64 && (points[1] - 1_f64).abs() < 1e-9_f64);
+        assert_eq!(Polynomial::from("x^3").critical_points(), vec![0_f64]);
+        assert!(Polynomial::from("x^3 + x").critical_points().is_empty());
+        assert!(Polynomial::from("2x + 1").critical_points().is_empty());
+    }
+
+    // Tests which way a parabola opens
+    #[test]
+    fn opens_upward() {
+        assert_eq!(Polynomial::from("x^2").opens_upward(), Some(true));
+        assert_eq!(Polynomial::from("-x^2").opens_upward(), Some(false));
+        assert_eq!(Polynomial::from("4x + 4").opens_upward(), None);
+        assert_eq!(Polynomial::from("x^3 + x^2").opens_upward(), None);
+    }
+
+    // Tests that a coefficent overflowing to infinity is an error
+    #[test]
+    fn coefficient_overflow() {
+        assert_eq!(
+            "1e400x^2 + 1".parse::<Polynomial>(),
+            Err(ParseError::CoefficientOverflow {
+                term: "1e400x^2".to_owned(),
+                span: 0..8
+            })
+        );
+        assert!(matches!(
+            "x^2 - sqrt(1e400)".parse::<Polynomial>(),
+            Err(ParseError::CoefficientOverflow { .. })
+        ));
+        assert!("1e300x^2 + 1".parse::<Polynomial>().is_ok());
+    }
+
+    // Tests splitting a polynomial into square-free factors
+    #[test]
+    fn square_free_decomposition() {
+        let poly = Polynomial::from_factored("(x - 1)^2(x + 2)").unwrap();
+        assert_eq!(
+            poly.square_free_decomposition(),
+            vec![
+                (Polynomial::from("x + 2"), 1),
+                (Polynomial::from("x - 1"), 2)
+            ]
+        );
+        let poly = Polynomial::from_factored("3(x - 1)(x + 1)(x - 2)^3").unwrap();
+        let factors = poly.square_free_decomposition();
+        let multiplicities: Vec<u32> = factors.iter().map(|(_, x)| *x).collect();
+        assert_eq!(multiplicities, vec![1, 3]);
+        for (factor, expected) in factors.iter().zip(&["x^2 - 1", "x - 2"]) {
+            let difference = &factor.0 - &Polynomial::from(*expected);
+            assert!(difference.coefficients.iter().all(|x| x.abs() < 1e-9_f64));
+        }
+        assert_eq!(
+            Polynomial::from("x^2 + 1").square_free_decomposition(),
+            vec![(Polynomial::from("x^2 + 1"), 1)]
+        );
+        assert!(Polynomial::from("5").square_free_decomposition().is_empty());
+    }
+
+    // Tests the greatest common divisor of polynomials
+    #[test]
+    fn gcd() {
+        assert_eq!(
+            Polynomial::from("x^2 - 1").gcd(&Polynomial::from("x - 1")),
+            Polynomial::from("x - 1")
+        );
+        assert_eq!(
+            Polynomial::from("2x^2 - 2").gcd(&Polynomial::from("3x^2 + 6x + 3")),
+            Polynomial::from("x + 1")
+        );
+        assert_eq!(
+            Polynomial::from("x^2 + 1").gcd(&Polynomial::from("x - 1")),
+            Polynomial::from("1")
+        );
+        assert_eq!(
+            Polynomial::from("4x - 2").gcd(&Polynomial::from("0")),
+            Polynomial::from("x - 0.5")
+        );
+
+        // Roots that aren't exact in binary still leave a common factor
+        let lhs = Polynomial::from_factored("(x - 0.1)(x - 0.7)").unwrap();
+        let rhs = Polynomial::from_factored("(x - 0.1)(x + 0.3)").unwrap();
+        let gcd = lhs.gcd(&rhs);
+        assert_eq!(gcd.degree(), 1);
+        assert!((gcd.coefficient(0) + 0.1_f64).abs() < 1e-9_f64);
+    }
+
+    // Tests polynomial long division
+    #[test]
+    fn div_rem() {
+        assert_eq!(
+            Polynomial::from("x^2 - 1").div_rem(&Polynomial::from("x - 1")),
+            (Polynomial::from("x + 1"), Polynomial::from("0"))
+        );
+        assert_eq!(
+            Polynomial::from("x^3 + 2x + 5").div_rem(&Polynomial::from("x^2 + 1")),
+            (Polynomial::from("x"), Polynomial::from("x + 5"))
+        );
+        assert_eq!(
+            Polynomial::from("4x^2 + 2").div_rem(&Polynomial::from("2")),
+            (Polynomial::from("2x^2 + 1"), Polynomial::from("0"))
+        );
+        assert_eq!(
+            Polynomial::from("x + 3").div_rem(&Polynomial::from("x^2")),
+            (Polynomial::from("0"), Polynomial::from("x + 3"))
+        );
+        assert_eq!(
+            Polynomial::from("x^2").checked_div_rem(&Polynomial::from("0")),
+            None
+        );
+    }
+
+    // Tests factoring -1 out of a negative leading coefficient
+    #[test]
+    fn positive_leading_string() {
+        assert_eq!(
+            Polynomial::from("-x^2 + 4x - 4").to_positive_leading_string(),
+            "-(x^2 - 4x + 4)"
+        );
+        assert_eq!(
+            Polynomial::from("-2x").to_positive_leading_string(),
+            "-(2x)"
+        );
+        assert_eq!(
+            Polynomial::from("x^2 - 1").to_positive_leading_string(),
+            "x^2 - 1"
+        );
+        assert_eq!(Polynomial::from("0").to_positive_leading_string(), "0");
+    }
+
+    // Tests finding coefficients too small to display
+    #[test]
+    fn negligible_coefficients() {
+        assert!(Polynomial::from("x^2 + 0.1x + 0.2x - 0.3x").has_negligible_coefficients());
+        assert!(!Polynomial::from("x^2 + 0.5x - 0.5x").has_negligible_coefficients());
+        assert!(!Polynomial::from("0.000000001x^2 + 1").has_negligible_coefficients());
+    }
+
+    // Tests iterating over the nonzero terms
+    #[test]
+    fn terms() {
+        assert_eq!(
+            Polynomial::from("x^2 + 4x + 4").terms().collect::<Vec<_>>(),
+            vec![(2, 1_f64), (1, 4_f64), (0, 4_f64)]
+        );
+        assert_eq!(
+            Polynomial::from("-x^3 + 2").terms().collect::<Vec<_>>(),
+            vec![(3, -1_f64), (0, 2_f64)]
+        );
+        assert_eq!(Polynomial::from("x - x").terms().count(), 0);
+
+        // Degrees past 255 aren't truncated
+        let mut coefficients = vec![0_f64; 401];
+        coefficients[0] = 1_f64;
+        let poly = Polynomial::from_coefficients(&coefficients);
+        assert_eq!(poly.terms().collect::<Vec<_>>(), vec![(400, 1_f64)]);
+    }
+
+    // Tests applying a function to every coefficient
+    #[test]
+    fn map_coefficients() {
+        let poly = Polynomial::from("x^2 + 4x + 4");
+        assert_eq!(
+            poly.map_coefficients(|_, coefficient| 2_f64 * coefficient),
+            Polynomial::from("2x^2 + 8x + 8")
+        );
+        assert_eq!(
+            poly.map_coefficients(|degree, coefficient| if degree == 2 {
+                0_f64
+            } else {
+                coefficient
+            }),
+            Polynomial::from("4x + 4")
+        );
+        assert_eq!(
+            Polynomial::from("x^2 + 1").map_coefficients(|degree, _| degree as f64),
+            Polynomial::from("2x^2 + x")
+        );
+
+        // Degrees past 255 aren't truncated
+        let poly = Polynomial::from_coefficients(&vec![1_f64; 401]);
+        let degrees = poly.map_coefficients(|degree, _| degree as f64);
+        assert_eq!(degrees.coefficient(400), 400_f64);
+    }
+
+    // Tests square roots of numbers as coefficents
+    #[test]
+    fn square_root_coefficient() {
+        let root = 2_f64.sqrt().sqrt();
+        crate::assert_roots!("x^2 - sqrt(2)", [root, -root], 1e-12);
+        assert_eq!(
+            Polynomial::from("√4x^2 - sqrt(9)x + sqrt(0.25)"),
+            Polynomial::from("2x^2 - 3x + 0.5")
+        );
+        assert_eq!(Polynomial::from("-√16"), Polynomial::from("-4"));
+        for input in &["2sqrt(2)", "sqrt(sqrt(2))", "sqrt(-2)", "sqrt(2"] {
+            assert!(matches!(
+                input.parse::<Polynomial>(),
+                Err(ParseError::InvalidCoefficient { .. })
+            ));
+        }
+    }
+
+    // Tests arithmetic in a parenthesized coefficent
+    #[test]
+    fn arithmetic_coefficient() {
+        assert_eq!(Polynomial::from("(3+2)x^2").coefficient(2), 5_f64);
+        assert_eq!(
+            Polynomial::from("(3 + 2)x^2 - (2*3)x + (1/2)"),
+            Polynomial::from("5x^2 - 6x + 0.5")
+        );
+        assert_eq!(
+            Polynomial::from("-(4-1)x + x^2"),
+            Polynomial::from("x^2 - 3x")
+        );
+        assert_eq!(Polynomial::from("((1+1)*3 - -1)x"), Polynomial::from("7x"));
+        assert!(matches!(
+            "(3+2x^2".parse::<Polynomial>(),
+            Err(ParseError::InvalidCoefficient { .. })
+        ));
+        assert_eq!(
+            "(1/0)x + 1".parse::<Polynomial>(),
+            Err(ParseError::DivisionByZero {
+                term: "(1/0)x".into(),
+                span: 0..6
+            })
+        );
+    }
+
+    // Tests a negative coefficent with a thousands separator
+    #[test]
+    fn negative_thousands_separator() {
+        assert_eq!(
+            Polynomial::from("-1,000x + 2"),
+            Polynomial::from("-1000x + 2")
+        );
+    }
+
+    // Tests adding up terms of the same degree, including ones that cancel out
+    #[test]
+    fn repeated_terms() {
+        assert_eq!(Polynomial::from("x^2 + x^2"), Polynomial::from("2x^2"));
+        assert_eq!(Polynomial::from("x^2 + x^2").roots(), (0_f64, 0_f64));
+        assert_eq!(Polynomial::from("x^2 - x^2"), Polynomial::from("0"));
+        assert_eq!(
+            Polynomial::from("x^2 - x^2").root_kind(),
+            RootKind::Infinite
+        );
+        assert_eq!(
+            Polynomial::from("3x^2 - 3x^2 + 4").root_kind(),
+            RootKind::NoRoots
+        );
+
+        // A cancelled leading term solves as a lower degree polynomial
+        let poly = Polynomial::from("x^2 - x^2 + 2x - 1");
+        assert_eq!(poly.degree(), 1);
+        assert_eq!(poly.roots().0, 0.5_f64);
+        assert_eq!(poly.all_roots(), vec![Complex::real(0.5_f64)]);
+        assert_eq!(
+            Polynomial::from("2x^2 + x = 2x^2 + 3"),
+            Polynomial::from("x - 3")
+        );
+    }
+
+    // Tests skipping a leading "f(x) =" naming the polynomial
+    #[test]
+    fn function_prefix() {
+        assert_eq!(
+            Polynomial::from("f(x) = x^2 - 1"),
+            Polynomial::from("x^2 - 1")
+        );
+        assert_eq!(Polynomial::from("y = x^2 - 1"), Polynomial::from("x^2 - 1"));
+        assert_eq!(Polynomial::from("g(x)=2x = 4"), Polynomial::from("2x - 4"));
+        assert_eq!(function_prefix_length("f(x) = x"), 6);
+        assert_eq!(function_prefix_length("x = 3"), 0);
+        assert_eq!(Polynomial::from("x = 3"), Polynomial::from("x - 3"));
+        assert_eq!(
+            "y = x^2 = 1 = 2".parse::<Polynomial>().unwrap_err().span(),
+            12..13
+        );
+        assert_eq!("f(x) = 2y".parse::<Polynomial>().unwrap_err().span(), 7..9);
+    }
+
+    // Tests coefficients written with a leading decimal point, with or without a sign
+    #[test]
+    fn leading_decimal_point() {
+        assert_eq!(Polynomial::from(".5x").coefficients(), vec![0.5_f64, 0_f64]);
+        assert_eq!(
+            Polynomial::from("-.5x").coefficients(),
+            vec![-0.5_f64, 0_f64]
+        );
+        assert_eq!(
+            Polynomial::from("+.5x^2").coefficients(),
+            vec![0.5_f64, 0_f64, 0_f64]
+        );
+        assert_eq!(
+            Polynomial::from("x^2 -.5x +.25").coefficients(),
+            vec![1_f64, -0.5_f64, 0.25_f64]
+        );
+        assert_eq!(
+            Polynomial::from("x - .5 = -.5x").coefficients(),
+            vec![1.5_f64, -0.5_f64]
+        );
+        assert!("-.x".parse::<Polynomial>().is_err());
+    }
+
+    // Tests stripping a semicolon or period ending the polynomial
+    #[test]
+    fn trailing_punctuation() {
+        let expected = Polynomial::from("x^2 - 1");
+        assert_eq!(Polynomial::from("x^2 - 1;"), expected);
+        assert_eq!(Polynomial::from("x^2 - 1."), expected);
+        assert_eq!(Polynomial::from("x^2 - 1 . ; "), expected);
+        assert_eq!(
+            Polynomial::from("x^2 - 1.5."),
+            Polynomial::from("x^2 - 1.5")
+        );
+        assert_eq!(Polynomial::from("x^2."), Polynomial::from("x^2"));
+        assert_eq!(Polynomial::from("x^2 = 1;"), expected);
+        assert_eq!(
+            Polynomial::from_factored("(x + 1)(x - 1).").unwrap(),
+            expected
+        );
+        assert_eq!(strip_trailing_punctuation("x^2 - 1.;"), "x^2 - 1");
+        assert_eq!(strip_trailing_punctuation("x^2 - 1.."), "x^2 - 1.");
+        assert_eq!(strip_trailing_punctuation("x^2 - 1"), "x^2 - 1");
+    }
+
+    // Tests an uppercase X as the variable
+    #[test]
+    fn uppercase_variable() {
+        assert_eq!(
+            Polynomial::from("X^2 + 4X + 4"),
+            Polynomial::from("x^2 + 4x + 4")
+        );
+        assert_eq!(
+            Polynomial::from("1E2X - 2e1"),
+            Polynomial::from("100x - 20")
+        );
+    }
+
+    // Tests recording which parts of a term the parser filled in
+    #[test]
+    fn term_parse_debug() {
+        let options = ParseOptions::default();
+        assert_eq!(
+            parse_term_debug("x", &options),
+            TermParse {
+                coefficient: "1".to_owned(),
+                degree: "1".to_owned(),
+                coefficient_defaulted: true,
+                degree_inferred: true,
+                implicit_exponent: false,
+            }
+        );
+        let term = parse_term_debug("-3x^2", &options);
+        assert_eq!(
+            (term.coefficient.as_str(), term.degree.as_str()),
+            ("-3", "2")
+        );
+        assert!(!term.coefficient_defaulted && !term.degree_inferred);
+        let term = parse_term_debug("4", &options);
+        assert_eq!(term.degree, "0");
+        assert!(term.degree_inferred);
+        let options = ParseOptions {
+            implicit_exponent: true,
+            ..ParseOptions::default()
+        };
+        let term = parse_term_debug("x3", &options);
+        assert_eq!(term.degree, "3");
+        assert!(term.implicit_exponent && !term.degree_inferred);
+    }
+
+    // Tests reading digits after the x as its degree when implicit exponents are enabled
+    #[test]
+    fn implicit_exponent() {
+        let options = ParseOptions {
+            implicit_exponent: true,
+            ..ParseOptions::default()
+        };
+        assert_eq!(
+            Polynomial::parse_with("3x2 - x1 + 4", &options),
+            Ok(Polynomial::from("3x^2 - x + 4"))
+        );
+        assert_eq!(
+            Polynomial::parse_with("x^2 + 2x", &options),
+            Ok(Polynomial::from("x^2 + 2x"))
+        );
+        assert_eq!(
+            Polynomial::parse_with("3x2", &ParseOptions::default()),
+            Ok(Polynomial::from("3x"))
+        );
+    }
+
+    // Tests getting every root as a complex number
+    #[test]
+    fn all_roots() {
+        assert_eq!(
+            Polynomial::from("x^2 + 4x + 4").all_roots(),
+            vec![Complex::real(-2_f64), Complex::real(-2_f64)]
+        );
+        assert_eq!(
+            Polynomial::from("x^2 + 2x + 5").all_roots(),
+            vec![Complex::new(-1_f64, -2_f64), Complex::new(-1_f64, 2_f64)]
+        );
+        assert_eq!(
+            Polynomial::from("2x - 1").all_roots(),
+            vec![Complex::real(0.5_f64)]
+        );
+        assert_eq!(Polynomial::from("x^4 - 1").all_roots().len(), 4);
+        assert!(Polynomial::from("3").all_roots().is_empty());
+    }
+
+    // Tests reading commas as decimal points, or as thousands separators by default
+    #[test]
+    fn decimal_comma() {
+        let options = ParseOptions {
+            decimal_comma: true,
+            ..ParseOptions::default()
+        };
+        let poly = Polynomial::parse_with("3,5x^2 - 1", &options).unwrap();
+        assert_eq!(poly.coefficient(2), 3.5_f64);
+        assert_eq!(
+            Polynomial::parse_with("1,000x - 2", &ParseOptions::default()),
+            Ok(Polynomial::from("1000x - 2"))
+        );
+    }
+
+    // Tests finding the roots of any degree numerically
+    #[test]
+    fn numeric_roots() {
+        let poly = Polynomial::from_factored("(x + 2)(x - 1)(x - 3)(x - 0.5)").unwrap();
+        let mut roots = poly.numeric_roots();
+        assert!(roots.iter().all(Complex::is_real));
+        roots.sort_by(|lhs, rhs| lhs.re.total_cmp(&rhs.re));
+        let expected = [-2_f64, 0.5_f64, 1_f64, 3_f64];
+        for (root, expected) in roots.iter().zip(expected.iter()) {
+            assert!((root.re - expected).abs() < 1e-9_f64);
+        }
+        sort_complex_roots(&mut roots);
+        assert_eq!(poly.all_roots(), roots);
+        assert_eq!(Polynomial::from("x^4 + 1").real_roots(), Vec::<f64>::new());
+    }
+
+    // Tests filtering the real roots to a range
+    #[test]
+    fn roots_in_range() {
+        let poly = Polynomial::from("x^2 - 4");
+        assert_eq!(poly.real_roots(), vec![2_f64, -2_f64]);
+        assert_eq!(poly.roots_in_range(0_f64, 10_f64), vec![2_f64]);
+        assert_eq!(poly.roots_in_range(-2_f64, 2_f64), vec![2_f64, -2_f64]);
+        assert!(Polynomial::from("x^2 + 4")
+            .roots_in_range(-10_f64, 10_f64)
+            .is_empty());
+    }
+
+    // Tests telling apart the kinds of roots, including when every term cancels
+    #[test]
+    fn root_kind() {
+        let poly = Polynomial::from("x - x");
+        assert_eq!(poly, Polynomial::from("0"));
+        assert_eq!(poly.root_kind(), RootKind::Infinite);
+        assert_eq!(Polynomial::from("3").root_kind(), RootKind::NoRoots);
+        assert_eq!(Polynomial::from("x^2 - 1").root_kind(), RootKind::Real);
+        assert_eq!(Polynomial::from("x^2 + 1").root_kind(), RootKind::Complex);
+        assert_eq!(Polynomial::from("x^3 - 1").root_kind(), RootKind::Complex);
+    }
+
+    // Tests the signs of the linear factors in factored forms
+    #[test]
+    fn linear_factor_signs() {
+        assert_eq!(linear_factor(3_f64), "x - 3");
+        assert_eq!(linear_factor(-3_f64), "x + 3");
+        assert_eq!(linear_factor(0_f64), "x");
+        assert_eq!(linear_factor(-0_f64), "x");
+        assert_eq!(linear_factor(0.00001_f64), "x");
+        assert_eq!(
+            Polynomial::from("x^2 - 9").factored_form(),
+            Some("(x + 3)(x - 3)".to_owned())
+        );
+        assert_eq!(
+            Polynomial::from("x^2 - 3x").factored_form(),
+            Some("x(x - 3)".to_owned())
+        );
+    }
+
+    // Tests getting the linear factors as (slope, intercept) pairs
+    #[test]
+    fn binomial_factors() {
+        assert_eq!(
+            Polynomial::from("x^2 - 1").binomial_factors(),
+            Some(vec![(1_f64, -1_f64), (1_f64, 1_f64)])
+        );
+        assert_eq!(
+            Polynomial::from("2x^2 - 3x + 1").binomial_factors(),
+            Some(vec![(2_f64, -2_f64), (1_f64, -0.5_f64)])
+        );
+        assert_eq!(
+            Polynomial::from("3x^2").binomial_factors(),
+            Some(vec![(3_f64, 0_f64), (1_f64, 0_f64)])
+        );
+        assert_eq!(Polynomial::from("x^2 + 1").binomial_factors(), None);
+        assert_eq!(Polynomial::from("x - 1").binomial_factors(), None);
+    }
+
+    // Tests a quadratic with only its quadratic term
+    #[test]
+    fn double_root_at_zero() {
+        assert!(Polynomial::from("3x^2").has_double_root_at_zero());
+        assert!(!Polynomial::from("3x^2 + x").has_double_root_at_zero());
+        assert!(!Polynomial::from("3x").has_double_root_at_zero());
+        assert_eq!(
+            Polynomial::from("3x^2").factored_form(),
+            Some("3x·x".to_owned())
+        );
+        assert_eq!(
+            Polynomial::from("-x^2").factored_form(),
+            Some("-x·x".to_owned())
+        );
+    }
+
+    // Tests evaluating the polynomial through a closure that outlives it
+    #[test]
+    fn closure() {
+        let f = Polynomial::from("x^2 - 3x + 2").as_fn();
+        assert_eq!(f(0_f64), 2_f64);
+        assert_eq!(f(1_f64), 0_f64);
+        assert_eq!(f(-1_f64), 6_f64);
+        assert_eq!(f(0.5_f64), 0.75_f64);
+        assert_eq!(f(10_f64), 72_f64);
+
+        // Generic code can take the closure as any other function
+        fn sum_at<F: Fn(f64) -> f64>(f: F, points: &[f64]) -> f64 {
+            points.iter().map(|x| f(*x)).sum()
+        }
+        assert_eq!(
+            sum_at(Polynomial::from("2x").as_fn(), &[1_f64, 2_f64]),
+            6_f64
+        );
+        assert_eq!(Polynomial::from("x - x").as_fn()(5_f64), 0_f64);
+    }
+
+    // Tests solving a quadratic whose x^2 terms cancel out as the linear polynomial it really is
+    #[test]
+    fn disguised_linear() {
+        let (root, other) = Polynomial::from("x^2 + 3x + 1 - x^2").roots();
+        assert!((root + 1_f64 / 3_f64).abs() < 1e-12);
+        assert!(other.is_nan());
+
+        // The x^2 terms leave a tiny leftover from rounding, which is ignored
+        let poly = Polynomial::from("0.1x^2 + 0.2x^2 - 0.3x^2 + 3x + 1");
+        assert_eq!(poly.degree(), 2);
+        assert_eq!(poly.effective_degree(), 1);
+        assert_eq!(poly.effective_polynomial(), Polynomial::from("3x + 1"));
+        let (root, other) = poly.roots();
+        assert!((root + 1_f64 / 3_f64).abs() < 1e-12);
+        assert!(other.is_nan());
+
+        // Small coefficients that are all that's there still count
+        let poly = Polynomial::from_coefficients(&[1e-13_f64, 1e-13_f64, 0_f64]);
+        assert_eq!(poly.effective_degree(), 2);
+        assert_eq!(poly.roots(), (0_f64, -1_f64));
+        assert_eq!(Polynomial::from("0.000000001x^2 + 1").effective_degree(), 2);
+        assert_eq!(Polynomial::from("x - x").effective_degree(), 0);
+
+        // Every degree decision is made at the effective degree
+        let poly = Polynomial::from("0.1x^2 + 0.2x^2 - 0.3x^2 + 3x - 3");
+        assert_eq!(poly.root_kind(), RootKind::Real);
+        assert_eq!(poly.all_roots(), vec![Complex::real(1_f64)]);
+        assert_eq!(poly.real_roots(), vec![1_f64]);
+        assert_eq!(poly.describe(), "A line with slope 3 and a root at x = 1.");
+    }
+
+    // Tests finding the roots after nudging one coefficient
+    #[test]
+    fn perturbed_roots() {
+        let poly = Polynomial::from("x^2 - 4");
+        assert_eq!(poly.roots_perturbed(0, 3_f64), (1_f64, -1_f64));
+        assert_eq!(poly.roots_perturbed(0, -5_f64), (3_f64, -3_f64));
+        assert_eq!(poly.roots_perturbed(0, 0_f64), poly.roots());
+        assert!(poly.roots_perturbed(0, 5_f64).0.is_nan());
+        assert_eq!(poly, Polynomial::from("x^2 - 4"));
+
+        // A coefficient above the degree adds a term, and cancelling the leading one removes it
+        assert_eq!(
+            Polynomial::from("x - 2").roots_perturbed(2, 1_f64),
+            Polynomial::from("x^2 + x - 2").roots()
+        );
+        let (root, _) = poly.roots_perturbed(1, 1_f64);
+        assert_eq!(root, Polynomial::from("x^2 + x - 4").roots().0);
+        assert!(Polynomial::from("x^2 + x - 2")
+            .roots_perturbed(2, -1_f64)
+            .1
+            .is_nan());
+    }
+
+    // Tests that roots close together are ill-conditioned
+    #[test]
+    fn root_condition_number() {
+        let separated = Polynomial::from("x^2 - 3x + 2").root_condition_number();
+        assert!(separated < 20_f64);
+        let close = Polynomial::from("x^2 - 2x + 0.999999").root_condition_number();
+        assert!(close > 100_f64 * separated);
+        assert!(Polynomial::from("x^2 - 2x + 1")
+            .root_condition_number()
+            .is_infinite());
+        assert_eq!(Polynomial::from("4").root_condition_number(), 0_f64);
+    }
+
+    // Tests finding the vertex and the extreme value of a parabola
+    #[test]
+    fn extreme_value() {
+        let poly = Polynomial::from("x^2 + 4x + 4");
+        assert_eq!(poly.vertex(), Some((-2_f64, 0_f64)));
+        assert_eq!(poly.extreme_value(), Some((Extremum::Min, 0_f64)));
+        let poly = Polynomial::from("-2x^2 + 4x + 1");
+        assert_eq!(poly.vertex(), Some((1_f64, 3_f64)));
+        assert_eq!(poly.extreme_value(), Some((Extremum::Max, 3_f64)));
+        assert_eq!(Polynomial::from("2x + 1").extreme_value(), None);
+    }
+
+    // Tests describing a polynomial in a sentence
+    #[test]
+    fn describe() {
+        assert_eq!(
+            Polynomial::from("x^2 + 4x + 4").describe(),
+            "An upward-opening parabola with vertex (-2, 0) and a double real root at x = -2."
+        );
+        assert_eq!(
+            Polynomial::from("-x^2 + 1").describe(),
+            "A downward-opening parabola with vertex (0, 1) and two real roots at x = -1 and \
+             x = 1."
+        );
+        assert_eq!(
+            Polynomial::from("x^2 + 1").describe(),
+            "An upward-opening parabola with vertex (0, 1) and no real roots."
+        );
+        assert_eq!(
+            Polynomial::from("2x - 1").describe(),
+            "A line with slope 2 and a root at x = 0.5."
+        );
+        assert_eq!(
+            Polynomial::from("-3").describe(),
+            "A constant -3, which has no roots."
+        );
+        assert_eq!(
+            Polynomial::from("x - x").describe(),
+            "The zero polynomial, which is zero for every x."
+        );
+        assert_eq!(
+            Polynomial::from("x^3 - x").describe(),
+            "A degree 3 polynomial with 3 distinct real roots."
+        );
+    }
+
+    // Tests finding where two polynomials intersect
+    #[test]
+    fn intersections() {
+        let poly = Polynomial::from("x^2");
+        assert_eq!(
+            poly.intersections(&Polynomial::from("2x - 1")),
+            vec![(1_f64, 1_f64)]
+        );
+        assert_eq!(
+            poly.intersections(&Polynomial::from("x + 2")),
+            vec![(-1_f64, 1_f64), (2_f64, 4_f64)]
+        );
+        assert!(poly.intersections(&Polynomial::from("-1")).is_empty());
+        assert!(poly.intersections(&poly).is_empty());
+    }
+
+    // Tests that roots have residuals near zero
+    #[test]
+    fn roots_with_residual() {
+        let roots = Polynomial::from("3x^2 - 7x + 1").roots_with_residual();
+        assert_eq!(roots.len(), 2);
+        assert!(roots.iter().all(|(_, residual)| residual.abs() < 1e-12_f64));
+        let roots = Polynomial::from("x^3 - 2x - 5").roots_with_residual();
+        assert_eq!(roots.len(), 1);
+        assert!(roots[0].1.abs() < 1e-12_f64);
+        assert!(Polynomial::from("x^2 + 1").roots_with_residual().is_empty());
+    }
+
+    // Tests that a caret without a degree after it is an error
+    #[test]
+    fn empty_degree() {
+        assert_eq!(
+            "x^".parse::<Polynomial>(),
+            Err(ParseError::InvalidDegree {
+                term: "x^".to_owned(),
+                span: 0..2,
+            })
+        );
+        assert_eq!(
+            "x^2 + 3x^".parse::<Polynomial>(),
+            Err(ParseError::InvalidDegree {
+                term: "+3x^".to_owned(),
+                span: 4..9,
+            })
+        );
+    }
+
+    // Tests that a sign before the first term applies to it
+    #[test]
+    fn leading_sign() {
+        let poly = Polynomial::from("+x^2 + 4x + 4");
+        assert_eq!(poly.coefficients(), vec![1_f64, 4_f64, 4_f64]);
+        let poly = Polynomial::from("-x^2 + 4x - 4");
+        assert_eq!(poly.coefficients(), vec![-1_f64, 4_f64, -4_f64]);
+        assert_eq!(poly.roots(), (2_f64, 2_f64));
+    }
+
+    // Tests that a run of signs before the first term folds into its net sign
+    #[test]
+    fn leading_sign_run() {
+        let expected = Polynomial::from("x^2 + 1");
+        assert_eq!(Polynomial::from("--x^2 + 1"), expected);
+        assert_eq!(Polynomial::from("- -x^2 + 1"), expected);
+        assert_eq!(Polynomial::from("-+x^2 + 1"), Polynomial::from("-x^2 + 1"));
+        assert_eq!(Polynomial::from("+-x^2 + 1"), Polynomial::from("-x^2 + 1"));
+        assert_eq!(Polynomial::from("---4"), Polynomial::from("-4"));
+        assert_eq!(Polynomial::from("x = --3"), Polynomial::from("x - 3"));
+        assert_eq!("--x^".parse::<Polynomial>().unwrap_err().span(), 0..4);
+    }
+
+    proptest::proptest! {
+        // Tests that parsing the displayed form of a polynomial gives the polynomial back
+        #[test]
+        fn display_round_trip(coefficients in proptest::collection::vec(-20_i32..=20, 0..6)) {
+            let coefficients: Vec<f64> = coefficients.into_iter().map(f64::from).collect();
+            let poly = Polynomial::from_coefficients(&coefficients);
+            let parsed: Polynomial = poly.to_string().parse().unwrap();
+            proptest::prop_assert_eq!(parsed.degree(), poly.degree());
+            for (lhs, rhs) in parsed.coefficients().iter().zip(poly.coefficients().iter()) {
+                proptest::prop_assert!((lhs - rhs).abs() <= TOLERANCE * rhs.abs());
+            }
+        }
+    }
+
+    // Tests that an equation is rearranged to equal zero
+    #[test]
+    fn equation_polynomial() {
+        let poly = Polynomial::from("x^2 - 1");
+        assert_eq!(Polynomial::from("x^2 - 1 = 0"), poly);
+        assert_eq!(Polynomial::from("x^2 = 1"), poly);
+
+        let error = "x^2 = 1 = 0".parse::<Polynomial>().unwrap_err();
+        assert_eq!(error, ParseError::UnexpectedEquals { span: 8..9 });
+    }
+
+    // Tests that the smaller root doesn't lose precision to cancellation
+    #[test]
+    fn stable_roots() {
+        let poly = Polynomial::from_coefficients(&[1_f64, 1e8_f64, 1_f64]);
+        let roots = poly.roots();
+
+        // The roots are -5e7 ± sqrt(2.5e15 - 1), which to 20 significant figures are
+        // -1.0000000000000000000e-8 and -99999999.999999990000
+        assert!((roots.0 - -1e-8_f64).abs() <= 1e-15 * 1e-8);
+        assert!((roots.1 - -99999999.99999999_f64).abs() <= 1e-15 * 1e8);
+    }
+
+    // Tests that huge coefficients don't overflow the discriminant
+    #[test]
+    fn huge_coefficients() {
+        let poly = Polynomial::from_coefficients(&[1e308_f64, -1.5e308_f64, 5e307_f64]);
+        assert!(!poly.discriminant().is_finite());
+        assert_eq!(poly.roots(), (1_f64, 0.5_f64));
+    }
+
+    // Tests detecting perfect squares
+    #[test]
+    fn perfect_square() {
+        assert!(Polynomial::from("x^2 + 4x + 4").is_perfect_square());
+        assert!(Polynomial::from("0.09x^2 - 0.6x + 1").is_perfect_square());
+        assert!(!Polynomial::from("x^2 + 4x + 5").is_perfect_square());
+        assert!(!Polynomial::from("-x^2 - 4x - 4").is_perfect_square());
+    }
+
+    // Tests creating a polynomial from its roots
+    #[test]
+    fn from_roots() {
+        assert_eq!(
+            Polynomial::from_roots(1_f64, &[1_f64, -1_f64]),
+            Polynomial::from("x^2 - 1")
+        );
+        assert_eq!(
+            Polynomial::from_roots(2_f64, &[-2_f64, -2_f64]),
+            Polynomial::from("2x^2 + 8x + 8")
+        );
+        assert_eq!(Polynomial::from_roots(3_f64, &[]), Polynomial::from("3"));
+    }
+
+    // Tests listing the quadratics with integer roots in a range
+    #[test]
+    fn integer_quadratics() {
+        let quadratics = enumerate_integer_quadratics(1_f64, -1, 1);
+        assert_eq!(quadratics.len(), 6);
+        assert_eq!(quadratics[0], Polynomial::from("x^2 + 2x + 1"));
+        assert_eq!(quadratics[2], Polynomial::from("x^2 - 1"));
+        assert!(quadratics.iter().all(|x| x.integer_roots().len() <= 2));
+        let unique: std::collections::HashSet<&Polynomial> = quadratics.iter().collect();
+        assert_eq!(unique.len(), 6);
+        assert_eq!(enumerate_integer_quadratics(2_f64, 0, 9).len(), 55);
+        assert!(enumerate_integer_quadratics(2_f64, 3, 2).is_empty());
+        assert!(enumerate_integer_quadratics(0_f64, 0, 3).is_empty());
+        assert!(enumerate_integer_quadratics(-3_f64, 1, 4)
+            .iter()
+            .all(|x| x.coefficient(2) == -3_f64));
+    }
+
+    // Tests creating a polynomial from (degree, coefficient) terms
+    #[test]
+    fn from_terms() {
+        assert_eq!(
+            Polynomial::from_terms(&[(2, 1_f64), (1, 4_f64), (0, 4_f64)]),
+            Polynomial::from("x^2 + 4x + 4")
+        );
+        assert_eq!(
+            Polynomial::from_terms(&[(0, 4_f64), (2, 1_f64), (0, -1_f64), (2, 2_f64)]),
+            Polynomial::from("3x^2 + 3")
+        );
+        assert_eq!(
+            Polynomial::from_terms(&[(3, 1_f64), (3, -1_f64), (1, 2_f64)]).degree(),
+            1
+        );
+        assert_eq!(Polynomial::from_terms(&[]), Polynomial::from("0"));
+    }
+
+    // Tests getting the coefficients highest degree first
+    #[test]
+    fn coefficients() {
+        let poly = Polynomial::from("x^2 + 4x + 4");
+        assert_eq!(poly.coefficients(), vec![1_f64, 4_f64, 4_f64]);
+        assert_eq!(Polynomial::from_coefficients(&poly.coefficients()), poly);
+        assert_eq!(
+            Polynomial::from("2x - 1").coefficients(),
+            vec![2_f64, -1_f64]
+        );
+    }
+
+    // Tests detecting when one polynomial is a multiple of another
+    #[test]
+    fn proportional_polynomials() {
+        let poly = Polynomial::from("2x^2 + 4x + 2");
+        assert_eq!(
+            poly.is_proportional_to(&Polynomial::from("x^2 + 2x + 1")),
+            Some(2_f64)
+        );
+        assert_eq!(
+            Polynomial::from("-x^2 + 1").is_proportional_to(&Polynomial::from("3x^2 - 3")),
+            Some(-1_f64 / 3_f64)
+        );
+        assert_eq!(poly.is_proportional_to(&Polynomial::from("x^2 + 2x")), None);
+        assert_eq!(poly.is_proportional_to(&Polynomial::from("x + 1")), None);
+        assert_eq!(poly.is_proportional_to(&Polynomial::from("0")), None);
+    }
+
+    // Tests checking for rational factors
+    #[test]
+    fn rational_factors() {
+        assert!(Polynomial::from("x^2 + 5x + 6").factors_over_rationals());
+        assert!(Polynomial::from("6x^2 - 5x + 1").factors_over_rationals());
+        assert!(!Polynomial::from("x^2 + x + 1").factors_over_rationals());
+        assert!(!Polynomial::from("x^2 - 2").factors_over_rationals());
+        assert!(!Polynomial::from("x^2 + 0.5x").factors_over_rationals());
+    }
+
+    // Tests the distance between the roots
+    #[test]
+    fn root_separation() {
+        assert_eq!(Polynomial::from("x^2 - 1").root_separation(), Some(2_f64));
+        assert_eq!(
+            Polynomial::from("x^2 + 4x + 4").root_separation(),
+            Some(0_f64)
+        );
+        assert_eq!(Polynomial::from("x^2 + 1").root_separation(), None);
+    }
+
+    // Tests telling apart distinct roots that display the same from a double root
+    #[test]
+    fn close_roots() {
+        let poly = Polynomial::from("x^2 - 2x + 0.9999999999");
+        assert!(poly.discriminant() > 0_f64);
+        assert!(poly.has_close_roots());
+        assert!(!Polynomial::from("x^2 - 2x + 1").has_close_roots());
+        assert!(!Polynomial::from("x^2 - 2x + 0.99").has_close_roots());
+        assert!(!Polynomial::from("x^2 - 2x + 1.0000000001").has_close_roots());
+    }
+
+    // Tests that zero coefficients are handled, including a zero leading coefficient
+    #[test]
+    fn zero_coefficients() {
+        assert_eq!(
+            Polynomial::from("x^2 + 0x + 9"),
+            Polynomial::from("x^2 + 9")
+        );
+        assert_eq!(
+            Polynomial::from("0x + x^2 + 0 + 9"),
+            Polynomial::from("x^2 + 9")
+        );
+
+        let poly = Polynomial::from("0x^2 + 4x + 4");
+        assert_eq!(poly.degree(), 1);
+        let roots = poly.roots();
+        assert_eq!(roots.0, -1_f64);
+        assert!(roots.1.is_nan());
+    }
+
+    // Tests composing two polynomials
+    #[test]
+    fn compose_polynomial() {
+        let poly = Polynomial::from("x^2");
+        let composed = poly.compose(&Polynomial::from("x + 1"));
+        assert_eq!(composed, Polynomial::from("x^2 + 2x + 1"));
+
+        let composed = composed.compose(&Polynomial::from("x^2"));
+        assert_eq!(composed, Polynomial::from("x^4 + 2x^2 + 1"));
+    }
+
+    // Tests the canonical display of polynomials
+    #[test]
+    fn display_polynomial() {
+        let poly = Polynomial::from("4 + x^2 - 4x - 0.5x^3");
+        assert_eq!(poly.to_string(), "-0.5x^3 + x^2 - 4x + 4");
+        assert_eq!(Polynomial::from("x - x").to_string(), "0");
+
+        // The leftover from 0.1 + 0.2 - 0.3 is hidden
+        let poly = Polynomial::from("x^2 + 0.1x + 0.2x - 0.3x + 4");
+        assert_ne!(poly.b(), 0_f64);
+        assert_eq!(poly.to_string(), "x^2 + 4");
+    }
+
+    // Tests rounding the coefficients to a number of decimal places
+    #[test]
+    fn round_coefficients() {
+        let poly = Polynomial::from("x^2 + 0.1x + 0.2x - 0.3x + 4");
+        assert_ne!(poly.coefficients(), vec![1_f64, 0_f64, 4_f64]);
+        assert_eq!(
+            poly.round_coefficients(6).coefficients(),
+            vec![1_f64, 0_f64, 4_f64]
+        );
+        let poly = Polynomial::from("1.23456x^2 - 2.5x + 0.4");
+        assert_eq!(
+            poly.round_coefficients(2).coefficients(),
+            vec![1.23_f64, -2.5_f64, 0.4_f64]
+        );
+        assert_eq!(
+            poly.round_coefficients(0).coefficients(),
+            vec![1_f64, -3_f64, 0_f64]
+        );
+        assert_eq!(poly.round_coefficients(400), poly);
+        assert_eq!(
+            Polynomial::from("0.4x + 1").round_coefficients(0),
+            Polynomial::from("1")
+        );
+    }
+
+    // Tests moving the curve of a polynomial up, down and sideways
+    #[test]
+    fn shift_polynomial() {
+        let poly = Polynomial::from("x^2");
+        assert_eq!(poly.shift_vertical(4_f64), Polynomial::from("x^2 + 4"));
+        assert_eq!(
+            Polynomial::from("x^2 + 4").shift_vertical(-4_f64),
+            Polynomial::from("x^2")
+        );
+        assert_eq!(
+            poly.shift_horizontal(2_f64),
+            Polynomial::from("x^2 - 4x + 4")
+        );
+        assert_eq!(
+            Polynomial::from("x^2 - 1").shift_horizontal(-1_f64).roots(),
+            (0_f64, -2_f64)
+        );
+    }
+
+    // Tests that equivalent polynomials are equal and hash the same, whatever the leftovers of
+    // cancelled terms
+    #[test]
+    fn canonical_hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashSet;
+
+        fn hash(poly: &Polynomial) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            poly.hash(&mut hasher);
+            hasher.finish()
+        }
+        let messy = Polynomial::from(
+            "x^2 + -0.5x + -2.5x + 2.5x + 0.5x + 4x + 8x - 4x -+-4x + 4 + 12 --+-8         -4",
+        );
+        let cancelled = Polynomial::from("0.1x^3 + 0.2x^3 - 0.3x^3 + 4 + x^2 + 4x");
+        assert_ne!(cancelled.coefficients(), messy.coefficients());
+        assert_eq!(
+            cancelled.canonical_coefficients(),
+            vec![1_f64, 4_f64, 4_f64]
+        );
+        assert_eq!(cancelled, messy);
+        assert_eq!(hash(&cancelled), hash(&messy));
+        assert_eq!(
+            Polynomial::from_coefficients(&[-0_f64, 1_f64, -0_f64]),
+            Polynomial::from("x")
+        );
+        assert_ne!(messy, Polynomial::from("x^2 + 4x + 5"));
+
+        let set: HashSet<Polynomial> = [messy, cancelled, Polynomial::from("x^2 - 1")]
+            .iter()
+            .cloned()
+            .collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    // Tests parsing a product of factors
+    #[test]
+    fn factored_polynomial() {
+        let poly = Polynomial::from_factored("-x(x + 2)^2").unwrap();
+        assert_eq!(poly, Polynomial::from("-x^3 - 4x^2 - 4x"));
+
+        let error = Polynomial::from_factored("(x + 2)(x - 3").unwrap_err();
+        assert_eq!(error, ParseError::UnbalancedParenthesis { span: 7..8 });
+    }
+
+    // Tests the LaTeX rendering of the quadratic equation
+    #[test]
+    fn latex_polynomial() {
+        let poly = Polynomial::from("x^2 + 4x + 4");
+        assert_eq!(poly.to_latex(), "x = \\frac{-4 \\pm \\sqrt{0}}{2}");
+
+        let poly = Polynomial::from("5x^2 + 4x + 4");
+        assert_eq!(poly.to_latex(), "x = \\frac{-4 \\pm \\sqrt{64}i}{10}");
+
+        assert_eq!(Polynomial::from("2x - 4").to_latex(), "x = \\frac{4}{2}");
+        assert_eq!(Polynomial::from("5").to_latex(), "\\text{no solution}");
+        assert_eq!(Polynomial::from("x - x").to_latex(), "x \\in \\mathbb{R}");
+    }
+
+    // Tests that complex roots come out in the same order however they were found
+    #[test]
+    fn canonical_root_order() {
+        for _ in 0..3 {
+            assert_eq!(
+                Polynomial::from("x^2 - 2x + 5").all_roots(),
+                vec![Complex::new(1_f64, -2_f64), Complex::new(1_f64, 2_f64)]
+            );
+            assert_eq!(
+                Polynomial::from("-x^2 + 2x - 5").all_roots(),
+                vec![Complex::new(1_f64, -2_f64), Complex::new(1_f64, 2_f64)]
+            );
+        }
+        let roots = Polynomial::from("x^4 - 1").all_roots();
+        let mut sorted = roots.clone();
+        sorted.reverse();
+        sort_complex_roots(&mut sorted);
+        assert_eq!(roots, sorted);
+        assert_eq!(
+            Polynomial::from("x^2 - 1").all_roots()[0],
+            Complex::real(-1_f64)
+        );
+    }
+
+    // Tests that sorting roots containing NaN is deterministic and keeps NaN last
+    #[test]
+    fn sort_roots_with_nan() {
+        let mut roots = [f64::NAN, 3_f64, -f64::NAN, -1_f64, 0_f64];
+        sort_roots(&mut roots);
+
+        assert_eq!(&roots[..3], &[-1_f64, 0_f64, 3_f64]);
+        assert!(roots[3].is_nan() && roots[4].is_nan());
+
+        let finite: Vec<f64> = roots.iter().cloned().filter(|x| !x.is_nan()).collect();
+        assert_eq!(finite, vec![-1_f64, 0_f64, 3_f64]);
+    }
+}
@@ -0,0 +1,160 @@
+use super::ParseError;
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use core::iter::Peekable;
+use core::ops::{Add, Div, Mul, Neg, Range, Sub};
+use core::str::Chars;
+
+// A number type that arithmetic in a coefficent can be evaluated over, so that f64 and exact
+// coefficents are read by the same evaluator
+pub(super) trait Number:
+    Sized
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    // Parses an unsigned number like 2.5, or None if it isn't one
+    fn parse_number(number: &str) -> Option<Self>;
+
+    // Checks if the number is zero, which can't be divided by
+    fn is_zero(&self) -> bool;
+}
+
+impl Number for f64 {
+    fn parse_number(number: &str) -> Option<Self> {
+        number.parse().ok()
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == 0_f64
+    }
+}
+
+// Why a coefficent couldn't be read
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum CoefficientError {
+    // It isn't a number, or its arithmetic is malformed
+    Invalid,
+    // Its arithmetic divides by zero
+    DivisionByZero,
+}
+
+impl CoefficientError {
+    // Gets the parse error for a term with this coefficent, given the span the term covers
+    pub(super) fn into_parse_error(self, term: &str, span: Range<usize>) -> ParseError {
+        let term = term.to_owned();
+        match self {
+            CoefficientError::Invalid => ParseError::InvalidCoefficient { term, span },
+            CoefficientError::DivisionByZero => ParseError::DivisionByZero { term, span },
+        }
+    }
+}
+
+// Evaluates arithmetic in parentheses like (3+2*4) or (10/(4-2)) with + - * / between numbers,
+// following the usual precedence, or an error if it's malformed or divides by zero. The whole
+// coefficent must be in the parentheses, since an operator after them isn't part of the term.
+pub(super) fn evaluate_arithmetic<T: Number>(expression: &str) -> Result<T, CoefficientError> {
+    let mut chars = expression.chars().peekable();
+    let value = arithmetic_sum(&mut chars)?;
+    if chars.next().is_some() {
+        return Err(CoefficientError::Invalid);
+    }
+    Ok(value)
+}
+
+// Evaluates terms added or subtracted together
+fn arithmetic_sum<T: Number>(chars: &mut Peekable<Chars>) -> Result<T, CoefficientError> {
+    let mut value = arithmetic_product(chars)?;
+    while let Some(&operator) = chars.peek() {
+        match operator {
+            '+' => {
+                chars.next();
+                value = value + arithmetic_product(chars)?;
+            }
+            '-' => {
+                chars.next();
+                value = value - arithmetic_product(chars)?;
+            }
+            _ => break,
+        }
+    }
+    Ok(value)
+}
+
+// Evaluates factors multiplied or divided together
+fn arithmetic_product<T: Number>(chars: &mut Peekable<Chars>) -> Result<T, CoefficientError> {
+    let mut value = arithmetic_factor(chars)?;
+    while let Some(&operator) = chars.peek() {
+        match operator {
+            '*' => {
+                chars.next();
+                value = value * arithmetic_factor(chars)?;
+            }
+            '/' => {
+                chars.next();
+                let divisor: T = arithmetic_factor(chars)?;
+                if divisor.is_zero() {
+                    return Err(CoefficientError::DivisionByZero);
+                }
+                value = value / divisor;
+            }
+            _ => break,
+        }
+    }
+    Ok(value)
+}
+
+// Evaluates a signed number, or arithmetic in parentheses
+fn arithmetic_factor<T: Number>(chars: &mut Peekable<Chars>) -> Result<T, CoefficientError> {
+    match chars.peek().ok_or(CoefficientError::Invalid)? {
+        '-' => {
+            chars.next();
+            arithmetic_factor(chars).map(|value: T| -value)
+        }
+        '+' => {
+            chars.next();
+            arithmetic_factor(chars)
+        }
+        '(' => {
+            chars.next();
+            let value = arithmetic_sum(chars)?;
+            match chars.next() {
+                Some(')') => Ok(value),
+                _ => Err(CoefficientError::Invalid),
+            }
+        }
+        _ => {
+            let mut number = String::new();
+            while let Some(&c) = chars.peek() {
+                if !c.is_ascii_digit() && c != '.' {
+                    break;
+                }
+                number.push(c);
+                chars.next();
+            }
+            T::parse_number(&number).ok_or(CoefficientError::Invalid)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Tests evaluating arithmetic with the usual precedence
+    #[test]
+    fn arithmetic_precedence() {
+        assert_eq!(evaluate_arithmetic("(2+3*4)"), Ok(14_f64));
+        assert_eq!(evaluate_arithmetic("((1+1)*3--1)"), Ok(7_f64));
+        assert_eq!(
+            evaluate_arithmetic::<f64>("(2+3"),
+            Err(CoefficientError::Invalid)
+        );
+        assert_eq!(
+            evaluate_arithmetic::<f64>("(1/(2-2))"),
+            Err(CoefficientError::DivisionByZero)
+        );
+    }
+}
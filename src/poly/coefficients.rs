@@ -0,0 +1,145 @@
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+use core::ops::{Deref, DerefMut};
+
+// The most coefficients stored inline, which is enough for a quadratic
+const INLINE_LENGTH: usize = 3;
+
+// The coefficients of a polynomial indexed by degree. Polynomials up to quadratics, which are by
+// far the most common, are stored inline so that they don't allocate.
+#[derive(Debug, Clone)]
+pub(super) enum Coefficients {
+    Quadratic {
+        values: [f64; INLINE_LENGTH],
+        length: usize,
+    },
+    General(Vec<f64>),
+}
+
+impl Coefficients {
+    // Creates an empty list of coefficients
+    pub(super) fn new() -> Self {
+        Coefficients::Quadratic {
+            values: [0_f64; INLINE_LENGTH],
+            length: 0,
+        }
+    }
+
+    // Creates a list of zero coefficients with the given length
+    pub(super) fn zeros(length: usize) -> Self {
+        let mut coefficients = Coefficients::new();
+        coefficients.resize(length);
+        coefficients
+    }
+
+    // Checks if the coefficients are stored inline rather than in a Vec
+    #[cfg(test)]
+    pub(super) fn is_inline(&self) -> bool {
+        matches!(self, Coefficients::Quadratic { .. })
+    }
+
+    // Adds the coefficient of the next degree, moving to a Vec once there are too many to store inline
+    pub(super) fn push(&mut self, coefficient: f64) {
+        match self {
+            Coefficients::Quadratic { values, length } if *length < INLINE_LENGTH => {
+                values[*length] = coefficient;
+                *length += 1;
+            }
+            Coefficients::Quadratic { values, .. } => {
+                let mut general = values.to_vec();
+                general.push(coefficient);
+                *self = Coefficients::General(general);
+            }
+            Coefficients::General(general) => general.push(coefficient),
+        }
+    }
+
+    // Removes the coefficient of the highest degree
+    pub(super) fn pop(&mut self) -> Option<f64> {
+        match self {
+            Coefficients::Quadratic { .. } if self.is_empty() => None,
+            Coefficients::Quadratic { values, length } => {
+                *length -= 1;
+                Some(values[*length])
+            }
+            Coefficients::General(general) => general.pop(),
+        }
+    }
+
+    // Extends the coefficients with zeros up to the given length
+    pub(super) fn resize(&mut self, length: usize) {
+        while self.len() < length {
+            self.push(0_f64);
+        }
+    }
+}
+
+impl Deref for Coefficients {
+    type Target = [f64];
+
+    fn deref(&self) -> &[f64] {
+        match self {
+            Coefficients::Quadratic { values, length } => &values[..*length],
+            Coefficients::General(general) => general,
+        }
+    }
+}
+
+impl DerefMut for Coefficients {
+    fn deref_mut(&mut self) -> &mut [f64] {
+        match self {
+            Coefficients::Quadratic { values, length } => &mut values[..*length],
+            Coefficients::General(general) => general,
+        }
+    }
+}
+
+// Coefficients are equal whether they're stored inline or not
+impl PartialEq for Coefficients {
+    fn eq(&self, other: &Coefficients) -> bool {
+        **self == **other
+    }
+}
+
+impl FromIterator<f64> for Coefficients {
+    fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> Self {
+        let mut coefficients = Coefficients::new();
+        for coefficient in iter {
+            coefficients.push(coefficient);
+        }
+        coefficients
+    }
+}
+
+impl From<Vec<f64>> for Coefficients {
+    fn from(general: Vec<f64>) -> Self {
+        if general.len() <= INLINE_LENGTH {
+            general.into_iter().collect()
+        } else {
+            Coefficients::General(general)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Polynomial;
+    use super::*;
+
+    // Tests that quadratics are stored without allocating a Vec, and larger polynomials aren't
+    #[test]
+    fn inline_quadratic() {
+        assert!(Polynomial::from("x^2 + 4x + 4").coefficients.is_inline());
+        assert!(Polynomial::from("4x + 4").coefficients.is_inline());
+        assert!(!Polynomial::from("x^3 - 1").coefficients.is_inline());
+        assert!((&Polynomial::from("x + 1") * &Polynomial::from("x - 1"))
+            .coefficients
+            .is_inline());
+
+        let mut coefficients: Coefficients = vec![1_f64, 2_f64, 3_f64].into();
+        coefficients.push(4_f64);
+        assert_eq!(*coefficients, [1_f64, 2_f64, 3_f64, 4_f64]);
+        assert_eq!(coefficients.pop(), Some(4_f64));
+        assert_eq!(coefficients, Coefficients::from(vec![1_f64, 2_f64, 3_f64]));
+    }
+}
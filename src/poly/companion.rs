@@ -0,0 +1,278 @@
+use super::Polynomial;
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::math::F64Ext;
+use crate::Complex;
+use alloc::vec;
+use alloc::vec::Vec;
+
+// The most QR steps spent on one eigenvalue before giving up
+const MAX_ITERATIONS: usize = 30;
+
+// Finds every root as the eigenvalues of the polynomial's companion matrix, whose characteristic
+// polynomial is the polynomial itself. Real roots have an imaginary part of exactly 0. Returns None
+// if the eigenvalues don't converge.
+#[allow(clippy::needless_range_loop)]
+pub(super) fn companion_roots(polynomial: &Polynomial) -> Option<Vec<Complex>> {
+    let degree = polynomial.degree();
+    if degree == 0 {
+        return Some(Vec::new());
+    }
+
+    // The matrix is indexed from 1 to match the usual statement of the algorithms below. Its
+    // first row holds the coefficients of the monic polynomial, with ones below the diagonal.
+    let leading = polynomial.coefficient(degree);
+    let mut matrix = vec![vec![0_f64; degree + 1]; degree + 1];
+    for j in 1..=degree {
+        matrix[1][j] = -polynomial.coefficient(degree - j) / leading;
+    }
+    for i in 2..=degree {
+        matrix[i][i - 1] = 1_f64;
+    }
+    balance(&mut matrix, degree);
+    hessenberg_eigenvalues(&mut matrix, degree)
+}
+
+// Scales the rows and columns of a matrix by powers of two so that their norms are closer,
+// which reduces rounding error in its eigenvalues without changing them
+#[allow(clippy::needless_range_loop)]
+fn balance(matrix: &mut [Vec<f64>], n: usize) {
+    let radix = 2_f64;
+    let mut balanced = false;
+    while !balanced {
+        balanced = true;
+        for i in 1..=n {
+            let (mut column, mut row) = (0_f64, 0_f64);
+            for j in (1..=n).filter(|j| *j != i) {
+                column += matrix[j][i].abs();
+                row += matrix[i][j].abs();
+            }
+            if column == 0_f64 || row == 0_f64 {
+                continue;
+            }
+            let total = column + row;
+            let mut scale = 1_f64;
+            while column < row / radix {
+                scale *= radix;
+                column *= radix * radix;
+            }
+            while column > row * radix {
+                scale /= radix;
+                column /= radix * radix;
+            }
+            if (column + row) / scale < 0.95_f64 * total {
+                balanced = false;
+                for j in 1..=n {
+                    matrix[i][j] /= scale;
+                    matrix[j][i] *= scale;
+                }
+            }
+        }
+    }
+}
+
+// Finds the eigenvalues of an upper Hessenberg matrix with shifted QR steps, deflating off each
+// real eigenvalue or complex pair as it converges. The matrix is destroyed in the process.
+#[allow(clippy::many_single_char_names, clippy::needless_range_loop)]
+fn hessenberg_eigenvalues(a: &mut [Vec<f64>], n: usize) -> Option<Vec<Complex>> {
+    let mut eigenvalues = vec![Complex::real(0_f64); n + 1];
+    let mut norm = 0_f64;
+    for i in 1..=n {
+        for j in (i - 1).max(1)..=n {
+            norm += a[i][j].abs();
+        }
+    }
+
+    let mut nn = n;
+    // The sum of the exceptional shifts, which are added back to each eigenvalue
+    let mut shift = 0_f64;
+    while nn >= 1 {
+        let mut iterations = 0;
+        loop {
+            // Look for a small subdiagonal element that splits the matrix
+            let mut l = nn;
+            while l >= 2 {
+                let mut s = a[l - 1][l - 1].abs() + a[l][l].abs();
+                if s == 0_f64 {
+                    s = norm;
+                }
+                if a[l][l - 1].abs() + s == s {
+                    a[l][l - 1] = 0_f64;
+                    break;
+                }
+                l -= 1;
+            }
+
+            let mut x = a[nn][nn];
+            if l == nn {
+                // One real eigenvalue has converged
+                eigenvalues[nn] = Complex::real(x + shift);
+                nn -= 1;
+                break;
+            }
+            let mut y = a[nn - 1][nn - 1];
+            let mut w = a[nn][nn - 1] * a[nn - 1][nn];
+            if l == nn - 1 {
+                // Two eigenvalues have converged, as a real pair or a complex conjugate pair
+                let p = 0.5_f64 * (y - x);
+                let q = p * p + w;
+                let z = q.abs().sqrt();
+                x += shift;
+                if q >= 0_f64 {
+                    let z = p + z.copysign(p);
+                    eigenvalues[nn - 1] = Complex::real(x + z);
+                    eigenvalues[nn] = Complex::real(if z != 0_f64 { x - w / z } else { x + z });
+                } else {
+                    eigenvalues[nn - 1] = Complex::new(x + p, -z);
+                    eigenvalues[nn] = Complex::new(x + p, z);
+                }
+                nn -= 2;
+                break;
+            }
+
+            if iterations == MAX_ITERATIONS {
+                return None;
+            }
+            if iterations == 10 || iterations == 20 {
+                // An exceptional shift, to break out of a cycle
+                shift += x;
+                for i in 1..=nn {
+                    a[i][i] -= x;
+                }
+                let s = a[nn][nn - 1].abs() + a[nn - 1][nn - 2].abs();
+                x = 0.75_f64 * s;
+                y = x;
+                w = -0.4375_f64 * s * s;
+            }
+            iterations += 1;
+
+            // Look for two consecutive small subdiagonal elements to start the double shift from
+            let mut m = nn - 2;
+            let (mut p, mut q, mut r) = loop {
+                let z = a[m][m];
+                let (rr, ss) = (x - z, y - z);
+                let p = (rr * ss - w) / a[m + 1][m] + a[m][m + 1];
+                let q = a[m + 1][m + 1] - z - rr - ss;
+                let r = a[m + 2][m + 1];
+                let s = p.abs() + q.abs() + r.abs();
+                let (p, q, r) = (p / s, q / s, r / s);
+                if m == l {
+                    break (p, q, r);
+                }
+                let u = a[m][m - 1].abs() * (q.abs() + r.abs());
+                let v = p.abs() * (a[m - 1][m - 1].abs() + z.abs() + a[m + 1][m + 1].abs());
+                if u + v == v {
+                    break (p, q, r);
+                }
+                m -= 1;
+            };
+            for i in m + 2..=nn {
+                a[i][i - 2] = 0_f64;
+                if i != m + 2 {
+                    a[i][i - 3] = 0_f64;
+                }
+            }
+
+            // A double shift QR step on rows l to nn and columns m to nn
+            for k in m..nn {
+                if k != m {
+                    p = a[k][k - 1];
+                    q = a[k + 1][k - 1];
+                    r = if k != nn - 1 { a[k + 2][k - 1] } else { 0_f64 };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x != 0_f64 {
+                        p /= x;
+                        q /= x;
+                        r /= x;
+                    }
+                }
+                let s = (p * p + q * q + r * r).sqrt().copysign(p);
+                if s == 0_f64 {
+                    continue;
+                }
+                if k == m {
+                    if l != m {
+                        a[k][k - 1] = -a[k][k - 1];
+                    }
+                } else {
+                    a[k][k - 1] = -s * x;
+                }
+                p += s;
+                x = p / s;
+                y = q / s;
+                let z = r / s;
+                q /= p;
+                r /= p;
+                for j in k..=nn {
+                    p = a[k][j] + q * a[k + 1][j];
+                    if k != nn - 1 {
+                        p += r * a[k + 2][j];
+                        a[k + 2][j] -= p * z;
+                    }
+                    a[k + 1][j] -= p * y;
+                    a[k][j] -= p * x;
+                }
+                for i in l..=nn.min(k + 3) {
+                    p = x * a[i][k] + y * a[i][k + 1];
+                    if k != nn - 1 {
+                        p += z * a[i][k + 2];
+                        a[i][k + 2] -= p * r;
+                    }
+                    a[i][k + 1] -= p * q;
+                    a[i][k] -= p;
+                }
+            }
+        }
+    }
+    eigenvalues.remove(0);
+    Some(eigenvalues)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Sorts roots by their real then imaginary parts, rounded so they can be compared exactly
+    fn rounded_roots(polynomial: &str) -> Vec<Complex> {
+        let mut roots: Vec<Complex> = companion_roots(&Polynomial::from(polynomial))
+            .unwrap()
+            .iter()
+            .map(|root| {
+                Complex::new(
+                    (root.re * 1e9_f64).round() / 1e9_f64 + 0_f64,
+                    (root.im * 1e9_f64).round() / 1e9_f64 + 0_f64,
+                )
+            })
+            .collect();
+        roots.sort_by(|lhs, rhs| lhs.re.total_cmp(&rhs.re).then(lhs.im.total_cmp(&rhs.im)));
+        roots
+    }
+
+    // Tests finding roots as eigenvalues of the companion matrix
+    #[test]
+    fn companion_matrix_roots() {
+        assert_eq!(
+            rounded_roots("x^4 - 1"),
+            vec![
+                Complex::real(-1_f64),
+                Complex::new(0_f64, -1_f64),
+                Complex::new(0_f64, 1_f64),
+                Complex::real(1_f64)
+            ]
+        );
+        assert_eq!(
+            rounded_roots("x^4 - 10x^3 + 35x^2 - 50x + 24"),
+            vec![
+                Complex::real(1_f64),
+                Complex::real(2_f64),
+                Complex::real(3_f64),
+                Complex::real(4_f64)
+            ]
+        );
+        assert_eq!(
+            rounded_roots("2x^2 - 2"),
+            vec![Complex::real(-1_f64), Complex::real(1_f64)]
+        );
+        assert_eq!(rounded_roots("x - 3"), vec![Complex::real(3_f64)]);
+        assert!(rounded_roots("7").is_empty());
+    }
+}
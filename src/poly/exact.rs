@@ -0,0 +1,135 @@
+use super::arithmetic::{evaluate_arithmetic, CoefficientError};
+use super::{split_equation, split_polynomial, split_subpolynomial, ParseError, ParseOptions};
+use alloc::format;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+
+// Represents a polynomial of the form Ax^2 + Bx + C with exact rational coefficents
+pub struct ExactPolynomial {
+    a: BigRational,
+    b: BigRational,
+    c: BigRational,
+}
+impl ExactPolynomial {
+    // Gets the discriminant (B^2 - 4AC) of the polynomial
+    pub fn discriminant(&self) -> BigRational {
+        &self.b * &self.b - BigRational::from_integer(4.into()) * &self.a * &self.c
+    }
+
+    // Gets the roots of the polynomial through the quadratic equation, or None if they are
+    // irrational or complex, or the polynomial isn't a quadratic
+    pub fn roots(&self) -> Option<(BigRational, BigRational)> {
+        if self.a.is_zero() {
+            return None;
+        }
+        let root = sqrt(&self.discriminant())?;
+        let denominator = BigRational::from_integer(2.into()) * &self.a;
+        let root1 = (-&self.b + &root) / &denominator;
+        let root2 = (-&self.b - &root) / &denominator;
+        Some((root1, root2))
+    }
+}
+
+// Gets the square root of a rational if it is a perfect square
+fn sqrt(value: &BigRational) -> Option<BigRational> {
+    if value.is_negative() {
+        return None;
+    }
+    let numer = value.numer().sqrt();
+    let denom = value.denom().sqrt();
+    if &(&numer * &numer) == value.numer() && &(&denom * &denom) == value.denom() {
+        Some(BigRational::new(numer, denom))
+    } else {
+        None
+    }
+}
+
+// Parses a decimal string such as "-2.5", or arithmetic in parentheses like (3+2), into an exact
+// rational, or an error if it isn't one
+fn parse_rational(value: &str) -> Result<BigRational, CoefficientError> {
+    let (sign, magnitude) = match value.strip_prefix('-') {
+        Some(magnitude) => (-BigRational::one(), magnitude),
+        None => (BigRational::one(), value),
+    };
+    if magnitude.starts_with('(') {
+        return evaluate_arithmetic(magnitude).map(|value: BigRational| sign * value);
+    }
+    parse_decimal(value).ok_or(CoefficientError::Invalid)
+}
+
+// Parses a decimal string such as "-2.5" or "1e3" into an exact rational, or None if it isn't one
+fn parse_decimal(value: &str) -> Option<BigRational> {
+    let (mantissa, exponent) = match value.find(['e', 'E']) {
+        Some(i) => (&value[..i], value[i + 1..].parse::<i16>().ok()?),
+        None => (value, 0),
+    };
+    let (whole, fraction) = match mantissa.find('.') {
+        Some(i) => (&mantissa[..i], &mantissa[i + 1..]),
+        None => (mantissa, ""),
+    };
+    let numer: BigInt = format!("{}{}", whole, fraction).parse().ok()?;
+    let denom = num_traits::pow(BigInt::from(10), fraction.len());
+    let value = BigRational::new(numer, denom);
+    let scale = num_traits::pow(BigInt::from(10), exponent.unsigned_abs() as usize);
+    Some(if exponent < 0 {
+        value / BigRational::from_integer(scale)
+    } else {
+        value * BigRational::from_integer(scale)
+    })
+}
+
+// Exact coefficents are evaluated by the same arithmetic as f64 ones
+impl super::arithmetic::Number for BigRational {
+    fn parse_number(number: &str) -> Option<Self> {
+        parse_decimal(number)
+    }
+
+    fn is_zero(&self) -> bool {
+        Zero::is_zero(self)
+    }
+}
+
+impl ExactPolynomial {
+    // Parses a polynomial, or an equation which is rearranged so that it equals zero, using the
+    // given options. Terms above x^2 are errors, as are coefficents that aren't plain decimals,
+    // like square roots, since they can't be represented exactly.
+    pub fn parse(value: &str, options: &ParseOptions) -> Result<Self, ParseError> {
+        let mut polynomial = Self {
+            a: BigRational::zero(),
+            b: BigRational::zero(),
+            c: BigRational::zero(),
+        };
+
+        // Terms on the right of an equals sign are moved over to the left
+        for (side, (expression, offset)) in split_equation(value)?.into_iter().enumerate() {
+            for (subpoly, span) in split_polynomial(expression, options) {
+                let span = span.start + offset..span.end + offset;
+                let term = split_subpolynomial(&subpoly, options);
+                let mut coefficent = parse_rational(term.coefficient.trim_start_matches('+'))
+                    .map_err(|error| error.into_parse_error(&subpoly, span.clone()))?;
+                if side == 1 {
+                    coefficent = -coefficent;
+                }
+                match term.degree.parse::<u8>() {
+                    Ok(2) => polynomial.a += coefficent,
+                    Ok(1) => polynomial.b += coefficent,
+                    Ok(0) => polynomial.c += coefficent,
+                    Ok(_) => {
+                        return Err(ParseError::UnsupportedDegree {
+                            term: subpoly,
+                            span,
+                        })
+                    }
+                    Err(_) => {
+                        return Err(ParseError::InvalidDegree {
+                            term: subpoly,
+                            span,
+                        })
+                    }
+                }
+            }
+        }
+        Ok(polynomial)
+    }
+}
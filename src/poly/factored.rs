@@ -0,0 +1,91 @@
+use super::{
+    function_prefix_length, parse_expression, strip_trailing_punctuation, ParseError, ParseOptions,
+    Polynomial,
+};
+use alloc::borrow::ToOwned;
+
+impl Polynomial {
+    // Parses a product of factors such as "2(x + 1)(x - 3)^2", expanding it into a polynomial.
+    // Each factor in parentheses may be raised to a whole power, and any terms outside of the
+    // parentheses (like a leading scalar, or a lone x) are multiplied in as factors too. A leading
+    // "f(x) =" and trailing punctuation are skipped.
+    pub fn from_factored(value: &str) -> Result<Polynomial, ParseError> {
+        let value = strip_trailing_punctuation(value);
+        let mut product = Polynomial::constant(1_f64);
+        let mut index = function_prefix_length(value);
+        while index < value.len() {
+            let rest = &value[index..];
+            let (factor, length) = match rest.find('(') {
+                // The terms before the next parenthesis multiply in as a single factor
+                Some(open) if open > 0 => (parse_outer_factor(&rest[..open], index)?, open),
+                Some(_) => parse_parenthesized_factor(rest, index)?,
+                None => (parse_outer_factor(rest, index)?, rest.len()),
+            };
+            product = &product * &factor;
+            index += length;
+        }
+        Ok(product)
+    }
+}
+
+// Parses terms outside of any parentheses, found at the given index. A lone sign multiplies the
+// product by 1 or -1.
+fn parse_outer_factor(value: &str, offset: usize) -> Result<Polynomial, ParseError> {
+    if let Some(close) = value.find(')') {
+        return Err(ParseError::UnbalancedParenthesis {
+            span: offset + close..offset + close + 1,
+        });
+    }
+    match value.trim() {
+        "" | "+" => Ok(Polynomial::constant(1_f64)),
+        "-" => Ok(Polynomial::constant(-1_f64)),
+        _ => parse_expression(value, offset, &ParseOptions::default()),
+    }
+}
+
+// Parses the factor in parentheses at the start of the string, along with any power it is raised
+// to. Returns the factor and the length of the string it covered.
+fn parse_parenthesized_factor(
+    value: &str,
+    offset: usize,
+) -> Result<(Polynomial, usize), ParseError> {
+    let close = match value[1..].find(['(', ')']) {
+        Some(i) if value[1 + i..].starts_with(')') => 1 + i,
+        Some(i) => {
+            return Err(ParseError::UnbalancedParenthesis {
+                span: offset + 1 + i..offset + 2 + i,
+            })
+        }
+        None => {
+            return Err(ParseError::UnbalancedParenthesis {
+                span: offset..offset + 1,
+            })
+        }
+    };
+    let factor = parse_expression(&value[1..close], offset + 1, &ParseOptions::default())?;
+
+    // Read the power, if the factor has one
+    let mut length = close + 1;
+    let mut power: u8 = 1;
+    let after = &value[length..];
+    if let Some(exponent) = after.trim_start().strip_prefix('^') {
+        let whitespace = after.len() - after.trim_start().len();
+        let digits = exponent.len()
+            - exponent
+                .trim_start_matches(|c: char| c.is_ascii_digit())
+                .len();
+        length += whitespace + 1 + digits;
+        power = exponent[..digits]
+            .parse()
+            .map_err(|_| ParseError::InvalidDegree {
+                term: value[..length].to_owned(),
+                span: offset..offset + length,
+            })?;
+    }
+
+    let mut result = Polynomial::constant(1_f64);
+    for _ in 0..power {
+        result = &result * &factor;
+    }
+    Ok((result, length))
+}
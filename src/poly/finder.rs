@@ -0,0 +1,359 @@
+use super::companion::companion_roots;
+use super::{sort_roots, Coefficients, Polynomial, TOLERANCE};
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::math::F64Ext;
+use crate::Complex;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+
+// The most steps Newton's method takes looking for a root before giving up on it, which is also
+// how many times the Durand-Kerner method refines its roots
+const MAX_ITERATIONS: usize = 100;
+
+// How small an imaginary part must be, relative to the root, to be treated as rounding error
+const IMAGINARY_THRESHOLD: f64 = 1e-6;
+
+// A strategy for finding the roots of a polynomial. Each finds one root per degree (counting
+// multiplicity), with NaN in place of any root that is complex or can't be found.
+pub trait RootFinder {
+    fn find_roots(&self, polynomial: &Polynomial) -> Vec<f64>;
+}
+
+// Solves polynomials of degree at most 2 through the quadratic equation. Always finds two roots,
+// and a linear polynomial has NaN as its second.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct QuadraticFormula;
+
+// Solves cubics in closed form with Cardano's method, falling back to the quadratic equation for
+// lower degrees. Higher degrees aren't supported and have no roots found.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Cardano;
+
+// Solves polynomials of any degree by finding the real roots one at a time with Newton's method,
+// dividing each out, until a quadratic is left to solve exactly
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Newton;
+
+// Solves polynomials of any degree, such as quartics, by finding the eigenvalues of the companion
+// matrix numerically. The real roots are in ascending order, followed by NaN for each complex one.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CompanionMatrix;
+
+impl RootFinder for QuadraticFormula {
+    // The first root is the one from adding the square root of the discriminant
+    fn find_roots(&self, polynomial: &Polynomial) -> Vec<f64> {
+        let (a, b, c) = (polynomial.a(), polynomial.b(), polynomial.c());
+        if a == 0_f64 {
+            let root = if b == 0_f64 { f64::NAN } else { -c / b };
+            return vec![root, f64::NAN];
+        }
+
+        // Scaling the coefficients doesn't change the roots, and keeps the discriminant from
+        // overflowing when they are huge. Scaling by a power of two is exact.
+        let scale = power_of_two_below(a.abs().max(b.abs()).max(c.abs()));
+        let (a, b, c) = (a / scale, b / scale, c / scale);
+
+        // Without a linear term the roots are just the square roots of -C/A, which is more
+        // accurate than going through the discriminant. They're NaN if -C/A is negative.
+        if b == 0_f64 {
+            let root = (-c / a).sqrt();
+            return if a > 0_f64 {
+                vec![root, -root]
+            } else {
+                vec![-root, root]
+            };
+        }
+
+        // Subtracting the square root of the discriminant from -B loses precision when they're
+        // close, so only the larger root comes from the quadratic equation, and the smaller one
+        // comes from the product of the roots being C/A. B isn't zero here, and the square root has
+        // its sign, so Q can't be zero.
+        let root = (b * b - 4_f64 * a * c).sqrt();
+        let q = -0.5_f64 * (b + root.copysign(b));
+        let (larger, smaller) = (q / a, c / q);
+        if b.is_sign_negative() {
+            vec![larger, smaller]
+        } else {
+            vec![smaller, larger]
+        }
+    }
+}
+
+impl RootFinder for Cardano {
+    fn find_roots(&self, polynomial: &Polynomial) -> Vec<f64> {
+        match polynomial.degree() {
+            0..=2 => return QuadraticFormula.find_roots(polynomial),
+            3 => (),
+            degree => return vec![f64::NAN; degree],
+        }
+        let a = polynomial.coefficient(3);
+        let (b, c, d) = (polynomial.a() / a, polynomial.b() / a, polynomial.c() / a);
+
+        // Substituting x = t - b/3 gives the depressed cubic t^3 + pt + q
+        let shift = -b / 3_f64;
+        let p = c - b * b / 3_f64;
+        let q = 2_f64 * b * b * b / 27_f64 - b * c / 3_f64 + d;
+        let discriminant = q * q / 4_f64 + p * p * p / 27_f64;
+        if discriminant > 0_f64 {
+            // One real root and a pair of complex ones
+            let root = discriminant.sqrt();
+            let t = (-q / 2_f64 + root).cbrt() + (-q / 2_f64 - root).cbrt();
+            vec![t + shift, f64::NAN, f64::NAN]
+        } else if p == 0_f64 {
+            vec![shift, shift, shift]
+        } else {
+            // Three real roots, which are found with trigonometry to avoid complex cube roots
+            let radius = 2_f64 * (-p / 3_f64).sqrt();
+            let angle = (3_f64 * q / (p * radius)).clamp(-1_f64, 1_f64).acos() / 3_f64;
+            (0..3)
+                .map(|k| radius * (angle - 2_f64 * PI * k as f64 / 3_f64).cos() + shift)
+                .collect()
+        }
+    }
+}
+
+impl RootFinder for Newton {
+    fn find_roots(&self, polynomial: &Polynomial) -> Vec<f64> {
+        let mut roots = Vec::new();
+        let mut remaining = polynomial.clone();
+        while remaining.degree() > 2 {
+            match newton_root(&remaining) {
+                Some(root) => {
+                    remaining = deflate(&remaining, root);
+                    roots.push(root);
+                }
+                None => {
+                    // Without a real root, the rest are assumed to be complex
+                    roots.resize(polynomial.degree(), f64::NAN);
+                    return roots;
+                }
+            }
+        }
+        if remaining.degree() > 0 {
+            let mut last = QuadraticFormula.find_roots(&remaining);
+            last.truncate(remaining.degree());
+            roots.append(&mut last);
+        }
+        roots
+    }
+}
+
+impl RootFinder for CompanionMatrix {
+    fn find_roots(&self, polynomial: &Polynomial) -> Vec<f64> {
+        let mut roots: Vec<f64> = companion_roots(polynomial)
+            .unwrap_or_else(|| durand_kerner(polynomial))
+            .into_iter()
+            .map(|root| if root.is_real() { root.re } else { f64::NAN })
+            .collect();
+        sort_roots(&mut roots);
+        roots
+    }
+}
+
+// Looks for a real root with Newton's method, starting from zero
+fn newton_root(polynomial: &Polynomial) -> Option<f64> {
+    let derivative = polynomial.derivative();
+    let mut x = 0_f64;
+    for _ in 0..MAX_ITERATIONS {
+        let slope = derivative.evaluate(x);
+        if slope == 0_f64 {
+            // Nudge off of a flat point, where the next step is undefined
+            x += 1_f64;
+            continue;
+        }
+        let step = polynomial.evaluate(x) / slope;
+        x -= step;
+        if step.abs() <= TOLERANCE * x.abs().max(1_f64) {
+            return Some(x);
+        }
+    }
+    None
+}
+
+// Finds every root at once as complex numbers with the Durand-Kerner method, which repeatedly
+// refines each guess by dividing out the others. A root whose imaginary part is small enough to be
+// rounding error is treated as real.
+pub(super) fn durand_kerner(polynomial: &Polynomial) -> Vec<Complex> {
+    let degree = polynomial.degree();
+    let leading = polynomial.coefficient(degree);
+    // The starting guesses are powers of a number that is neither real nor a root of unity, so
+    // the guesses are spread out and none of them start out equal
+    let seed = Complex::new(0.4_f64, 0.9_f64);
+    let mut roots: Vec<Complex> = (0..degree)
+        .scan(Complex::real(1_f64), |guess, _| {
+            *guess = *guess * seed;
+            Some(*guess)
+        })
+        .collect();
+    for _ in 0..MAX_ITERATIONS {
+        let mut largest_step = 0_f64;
+        for i in 0..degree {
+            let z = roots[i];
+            let denominator = roots
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .fold(Complex::real(leading), |acc, (_, root)| acc * (z - *root));
+            let step = polynomial.evaluate_complex(z) / denominator;
+            roots[i] = z - step;
+            largest_step = largest_step.max(step.norm_squared() / z.norm_squared().max(1_f64));
+        }
+        if largest_step <= TOLERANCE * TOLERANCE {
+            break;
+        }
+    }
+    roots
+        .into_iter()
+        .map(|root| {
+            if root.im.abs() <= IMAGINARY_THRESHOLD * root.re.abs().max(1_f64) {
+                Complex::real(root.re)
+            } else {
+                root
+            }
+        })
+        .collect()
+}
+
+// Divides the factor (x - root) out of the polynomial with synthetic division, dropping the
+// remainder
+fn deflate(polynomial: &Polynomial, root: f64) -> Polynomial {
+    let mut quotient = Coefficients::zeros(polynomial.degree());
+    let mut carry = 0_f64;
+    for degree in (1..polynomial.coefficients.len()).rev() {
+        carry = carry * root + polynomial.coefficient(degree);
+        quotient[degree - 1] = carry;
+    }
+    Polynomial::new(quotient)
+}
+
+// Gets the largest power of two at most the given positive value, or 1 for subnormal values
+fn power_of_two_below(value: f64) -> f64 {
+    let power = f64::from_bits(value.to_bits() & 0x7ff0_0000_0000_0000);
+    if power == 0_f64 {
+        1_f64
+    } else {
+        power
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::poly::sort_roots;
+
+    // Sorts found roots, rounded so that they can be compared exactly
+    fn rounded_roots(finder: &dyn RootFinder, polynomial: &str) -> Vec<f64> {
+        let mut roots: Vec<f64> = finder
+            .find_roots(&Polynomial::from(polynomial))
+            .iter()
+            .map(|root| (root * 1e6_f64).round() / 1e6_f64)
+            .collect();
+        sort_roots(&mut roots);
+        roots
+    }
+
+    // Tests the shortcut for quadratics without a linear term
+    #[test]
+    fn quadratic_without_linear_term() {
+        assert_eq!(
+            QuadraticFormula.find_roots(&Polynomial::from("x^2 - 9")),
+            vec![3_f64, -3_f64]
+        );
+        assert_eq!(
+            QuadraticFormula.find_roots(&Polynomial::from("-2x^2 + 18")),
+            vec![-3_f64, 3_f64]
+        );
+        assert_eq!(
+            QuadraticFormula.find_roots(&Polynomial::from("x^2 - 2"))[0],
+            2_f64.sqrt()
+        );
+        assert!(QuadraticFormula
+            .find_roots(&Polynomial::from("x^2 + 9"))
+            .iter()
+            .all(|root| root.is_nan()));
+        assert_eq!(
+            Polynomial::from("x^2 + 9").all_roots(),
+            vec![Complex::new(0_f64, -3_f64), Complex::new(0_f64, 3_f64)]
+        );
+    }
+
+    // Tests the quadratic equation matches the polynomial's roots
+    #[test]
+    fn quadratic_formula() {
+        let polynomial = Polynomial::from("x^2 - 5x + 6");
+        let (root1, root2) = polynomial.roots();
+        assert_eq!(QuadraticFormula.find_roots(&polynomial), vec![root1, root2]);
+    }
+
+    // Tests solving cubics with Cardano's method
+    #[test]
+    fn cardano() {
+        assert_eq!(
+            rounded_roots(&Cardano, "x^3 - 6x^2 + 11x - 6"),
+            vec![1_f64, 2_f64, 3_f64]
+        );
+        assert_eq!(
+            rounded_roots(&Cardano, "x^3 - 3x + 2"),
+            vec![-2_f64, 1_f64, 1_f64]
+        );
+        let roots = rounded_roots(&Cardano, "x^3 - 1");
+        assert_eq!(roots[0], 1_f64);
+        assert!(roots[1].is_nan() && roots[2].is_nan());
+        assert_eq!(rounded_roots(&Cardano, "x^2 - 1"), vec![-1_f64, 1_f64]);
+    }
+
+    // Tests finding complex roots with the Durand-Kerner method
+    #[test]
+    fn durand_kerner_roots() {
+        let mut roots = durand_kerner(&Polynomial::from("x^3 - x^2 + x - 1"));
+        roots.sort_by(|lhs, rhs| lhs.im.total_cmp(&rhs.im));
+        let rounded: Vec<Complex> = roots
+            .iter()
+            .map(|root| {
+                Complex::new(
+                    (root.re * 1e6_f64).round() / 1e6_f64,
+                    (root.im * 1e6_f64).round() / 1e6_f64,
+                )
+            })
+            .collect();
+        assert_eq!(
+            rounded,
+            vec![
+                Complex::new(0_f64, -1_f64),
+                Complex::real(1_f64),
+                Complex::new(0_f64, 1_f64)
+            ]
+        );
+        assert!(roots[1].is_real());
+    }
+
+    // Tests solving quartics with the companion matrix
+    #[test]
+    fn companion_matrix() {
+        let roots = CompanionMatrix.find_roots(&Polynomial::from("x^4 - 1"));
+        assert_eq!(roots.len(), 4);
+        assert!((roots[0] + 1_f64).abs() < 1e-12_f64);
+        assert!((roots[1] - 1_f64).abs() < 1e-12_f64);
+        assert!(roots[2].is_nan() && roots[3].is_nan());
+        assert_eq!(
+            rounded_roots(&CompanionMatrix, "x^4 - 10x^3 + 35x^2 - 50x + 24"),
+            vec![1_f64, 2_f64, 3_f64, 4_f64]
+        );
+    }
+
+    // Tests solving higher degrees with Newton's method
+    #[test]
+    fn newton() {
+        assert_eq!(
+            rounded_roots(&Newton, "x^4 - 10x^2 + 9"),
+            vec![-3_f64, -1_f64, 1_f64, 3_f64]
+        );
+        assert_eq!(
+            rounded_roots(&Newton, "x^3 - 6x^2 + 11x - 6"),
+            vec![1_f64, 2_f64, 3_f64]
+        );
+        assert_eq!(rounded_roots(&Newton, "2x - 4"), vec![2_f64]);
+        assert!(rounded_roots(&Newton, "x^4 + 1").iter().all(|x| x.is_nan()));
+    }
+}
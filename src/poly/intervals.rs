@@ -0,0 +1,205 @@
+use super::{sort_roots, Polynomial};
+use alloc::vec;
+use alloc::vec::Vec;
+
+// An open interval lo < x < hi, where an infinite bound means the interval doesn't end
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+    pub lo: f64,
+    pub hi: f64,
+}
+
+impl Interval {
+    // Creates the interval lo < x < hi
+    pub fn new(lo: f64, hi: f64) -> Self {
+        Self { lo, hi }
+    }
+
+    // Gets a point inside the interval, in the middle if both bounds are finite. An unbounded side
+    // steps away from the bound in proportion to its size, since a step of 1 is lost to rounding
+    // next to a huge bound.
+    pub fn interior_point(&self) -> f64 {
+        match (self.lo.is_finite(), self.hi.is_finite()) {
+            (true, true) => (self.lo + self.hi) / 2_f64,
+            (true, false) => self.lo + self.lo.abs().max(1_f64),
+            (false, true) => self.hi - self.hi.abs().max(1_f64),
+            (false, false) => 0_f64,
+        }
+    }
+}
+
+// The sign of a polynomial's values over an interval
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sign {
+    Positive,
+    Negative,
+    // Only the zero polynomial is zero over an interval
+    Zero,
+}
+
+// Whether a polynomial rises or falls over an interval
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Monotonicity {
+    Increasing,
+    Decreasing,
+    // Only a constant polynomial neither rises nor falls over an interval
+    Constant,
+}
+
+impl Polynomial {
+    // Gets the sign of the polynomial over each interval between its distinct real roots, in
+    // ascending order, covering the whole line. The sign is found at a point inside each
+    // interval, so the intervals either side of a repeated root can share a sign.
+    pub fn sign_chart(&self) -> Vec<(Interval, Sign)> {
+        let mut roots = self.real_roots();
+        sort_roots(&mut roots);
+        roots.dedup();
+        let mut bounds = vec![f64::NEG_INFINITY];
+        bounds.extend(roots);
+        bounds.push(f64::INFINITY);
+        bounds
+            .windows(2)
+            .map(|bound| {
+                let interval = Interval::new(bound[0], bound[1]);
+                let value = self.evaluate(interval.interior_point());
+                let sign = if value > 0_f64 {
+                    Sign::Positive
+                } else if value < 0_f64 {
+                    Sign::Negative
+                } else {
+                    Sign::Zero
+                };
+                (interval, sign)
+            })
+            .collect()
+    }
+
+    // Gets whether the polynomial is increasing or decreasing over each interval between its
+    // critical points, in ascending order, covering the whole line. It's found from the sign of
+    // the derivative inside each interval, so a quadratic decreases then increases around the
+    // vertex of an upward-opening parabola.
+    pub fn monotonic_intervals(&self) -> Vec<(Interval, Monotonicity)> {
+        let mut bounds = vec![f64::NEG_INFINITY];
+        bounds.extend(self.critical_points());
+        bounds.push(f64::INFINITY);
+        let derivative = self.derivative();
+        bounds
+            .windows(2)
+            .map(|bound| {
+                let interval = Interval::new(bound[0], bound[1]);
+                let slope = derivative.evaluate(interval.interior_point());
+                let monotonicity = if slope > 0_f64 {
+                    Monotonicity::Increasing
+                } else if slope < 0_f64 {
+                    Monotonicity::Decreasing
+                } else {
+                    Monotonicity::Constant
+                };
+                (interval, monotonicity)
+            })
+            .collect()
+    }
+
+    // Gets the open intervals (lo, hi) of x where the polynomial is positive, in ascending order,
+    // with infinite bounds for intervals that don't end. The intervals either side of a repeated
+    // root are listed separately, and the zero polynomial is positive nowhere.
+    pub fn positive_intervals(&self) -> Vec<(f64, f64)> {
+        self.sign_chart()
+            .into_iter()
+            .filter(|(_, sign)| *sign == Sign::Positive)
+            .map(|(interval, _)| (interval.lo, interval.hi))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Tests that the sign alternates across the roots of x^2 - 1
+    #[test]
+    fn sign_chart() {
+        let inf = f64::INFINITY;
+        assert_eq!(
+            Polynomial::from("x^2 - 1").sign_chart(),
+            vec![
+                (Interval::new(-inf, -1_f64), Sign::Positive),
+                (Interval::new(-1_f64, 1_f64), Sign::Negative),
+                (Interval::new(1_f64, inf), Sign::Positive),
+            ]
+        );
+        assert_eq!(
+            Polynomial::from("x^2 - 2x + 1").sign_chart(),
+            vec![
+                (Interval::new(-inf, 1_f64), Sign::Positive),
+                (Interval::new(1_f64, inf), Sign::Positive),
+            ]
+        );
+        assert_eq!(
+            Polynomial::from("-x^2 - 1").sign_chart(),
+            vec![(Interval::new(-inf, inf), Sign::Negative)]
+        );
+        assert_eq!(
+            Polynomial::from("x - x").sign_chart(),
+            vec![(Interval::new(-inf, inf), Sign::Zero)]
+        );
+    }
+
+    // Tests finding where a polynomial rises and falls around its critical points
+    #[test]
+    fn monotonic_intervals() {
+        let inf = f64::INFINITY;
+        assert_eq!(
+            Polynomial::from("x^2 + 4x + 4").monotonic_intervals(),
+            vec![
+                (Interval::new(-inf, -2_f64), Monotonicity::Decreasing),
+                (Interval::new(-2_f64, inf), Monotonicity::Increasing),
+            ]
+        );
+        assert_eq!(
+            Polynomial::from("-x^2 + 2x").monotonic_intervals(),
+            vec![
+                (Interval::new(-inf, 1_f64), Monotonicity::Increasing),
+                (Interval::new(1_f64, inf), Monotonicity::Decreasing),
+            ]
+        );
+        assert_eq!(
+            Polynomial::from("-3x + 1").monotonic_intervals(),
+            vec![(Interval::new(-inf, inf), Monotonicity::Decreasing)]
+        );
+        assert_eq!(
+            Polynomial::from("5").monotonic_intervals(),
+            vec![(Interval::new(-inf, inf), Monotonicity::Constant)]
+        );
+    }
+
+    // Tests finding where a polynomial is positive from its sign chart
+    #[test]
+    fn positive_intervals() {
+        let inf = f64::INFINITY;
+        assert_eq!(
+            Polynomial::from("x^2 - 1").positive_intervals(),
+            vec![(-inf, -1_f64), (1_f64, inf)]
+        );
+        assert_eq!(
+            Polynomial::from("-x^2 + 1").positive_intervals(),
+            vec![(-1_f64, 1_f64)]
+        );
+        assert_eq!(
+            Polynomial::from("x^2 + 1").positive_intervals(),
+            vec![(-inf, inf)]
+        );
+        assert!(Polynomial::from("-x^2 - 1").positive_intervals().is_empty());
+        assert_eq!(
+            Polynomial::from("x - 2").positive_intervals(),
+            vec![(2_f64, inf)]
+        );
+        assert!(Polynomial::from("x - x").positive_intervals().is_empty());
+
+        // Stepping 1 past a root this large would land back on it
+        assert_eq!(
+            Polynomial::from("x^2 - 100000000000000000000000000000000").positive_intervals(),
+            vec![(-inf, -1e16_f64), (1e16_f64, inf)]
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use super::{Coefficients, Polynomial};
+use core::ops::{Add, Mul, Neg, Sub};
+
+impl<'a> Add<&'a Polynomial> for &'a Polynomial {
+    type Output = Polynomial;
+
+    fn add(self, other: &Polynomial) -> Polynomial {
+        let length = self.coefficients.len().max(other.coefficients.len());
+        Polynomial::new(
+            (0..length)
+                .map(|degree| self.coefficient(degree) + other.coefficient(degree))
+                .collect(),
+        )
+    }
+}
+
+impl<'a> Sub<&'a Polynomial> for &'a Polynomial {
+    type Output = Polynomial;
+
+    fn sub(self, other: &Polynomial) -> Polynomial {
+        self + &-other
+    }
+}
+
+impl<'a> Mul<&'a Polynomial> for &'a Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, other: &Polynomial) -> Polynomial {
+        if self.coefficients.is_empty() || other.coefficients.is_empty() {
+            return Polynomial::new(Coefficients::new());
+        }
+        let mut coefficients =
+            Coefficients::zeros(self.coefficients.len() + other.coefficients.len() - 1);
+        for (i, lhs) in self.coefficients.iter().enumerate() {
+            for (j, rhs) in other.coefficients.iter().enumerate() {
+                coefficients[i + j] += lhs * rhs;
+            }
+        }
+        Polynomial::new(coefficients)
+    }
+}
+
+impl Neg for &Polynomial {
+    type Output = Polynomial;
+
+    fn neg(self) -> Polynomial {
+        Polynomial::new(self.coefficients.iter().map(|x| -x).collect())
+    }
+}
+
+impl Add for Polynomial {
+    type Output = Polynomial;
+
+    fn add(self, other: Polynomial) -> Polynomial {
+        &self + &other
+    }
+}
+
+impl Sub for Polynomial {
+    type Output = Polynomial;
+
+    fn sub(self, other: Polynomial) -> Polynomial {
+        &self - &other
+    }
+}
+
+impl Mul for Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, other: Polynomial) -> Polynomial {
+        &self * &other
+    }
+}
+
+impl Neg for Polynomial {
+    type Output = Polynomial;
+
+    fn neg(self) -> Polynomial {
+        -&self
+    }
+}
@@ -0,0 +1,245 @@
+use super::{Polynomial, TOLERANCE};
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::math::F64Ext;
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+use core::fmt;
+
+// The largest magnitude a coefficient can have for its divisors to be searched, past which f64
+// can no longer represent every integer
+pub(super) const MAX_EXACT_INTEGER: f64 = 9_007_199_254_740_992_f64;
+
+// The most candidate rational roots searched, so that coefficients with thousands of divisors
+// can't take billions of steps
+const MAX_ROOT_CANDIDATES: usize = 100_000;
+
+// A fraction in lowest terms, with the sign on the numerator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    pub numerator: i64,
+    pub denominator: i64,
+}
+
+impl Rational {
+    // Creates a fraction, reducing it to lowest terms. Panics if the denominator is zero.
+    pub fn new(numerator: i64, denominator: i64) -> Self {
+        assert!(
+            denominator != 0,
+            "the denominator of a fraction can't be zero"
+        );
+        let divisor = gcd(numerator, denominator).max(1) * denominator.signum();
+        Self {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    // Gets the fraction as a float
+    pub fn to_f64(self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl Polynomial {
+    // Gets every candidate rational root ±p/q from the rational root theorem, where p divides the
+    // constant term and q divides the leading coefficient, in lowest terms and without repeats.
+    // A zero constant term makes 0 a candidate, and the search continues past the factor of x.
+    // Only polynomials with integer coefficients have candidates, and only the first
+    // MAX_ROOT_CANDIDATES are listed, so roots can be missed when the coefficients are huge.
+    pub fn rational_root_candidates(&self) -> Vec<Rational> {
+        let integer = self
+            .coefficients
+            .iter()
+            .all(|x| *x == x.round() && x.abs() <= MAX_EXACT_INTEGER);
+        if !integer || self.degree() == 0 {
+            return Vec::new();
+        }
+
+        let mut candidates = Vec::new();
+        let mut seen = BTreeSet::new();
+        let lowest = self.coefficients.iter().position(|x| *x != 0_f64).unwrap();
+        if lowest > 0 {
+            candidates.push(Rational::new(0, 1));
+        }
+        let constant = self.coefficients[lowest] as i64;
+        let leading = self.coefficient(self.degree()) as i64;
+        if lowest < self.degree() {
+            let denominators = divisors(leading);
+            for p in divisors(constant) {
+                for &q in &denominators {
+                    for candidate in [Rational::new(p, q), Rational::new(-p, q)] {
+                        if seen.insert((candidate.numerator, candidate.denominator)) {
+                            candidates.push(candidate);
+                        }
+                        if candidates.len() == MAX_ROOT_CANDIDATES {
+                            return candidates;
+                        }
+                    }
+                }
+            }
+        }
+        candidates
+    }
+
+    // Gets the exact rational roots, found by testing each candidate from the rational root
+    // theorem. A repeated root is only listed once.
+    pub fn rational_roots(&self) -> Vec<Rational> {
+        // Without real roots there's nothing to find, so the candidates aren't searched
+        if self.real_roots().is_empty() {
+            return Vec::new();
+        }
+        self.rational_root_candidates()
+            .into_iter()
+            .filter(|candidate| self.is_root(candidate.to_f64()))
+            .collect()
+    }
+
+    // Gets the integer roots in ascending order, found by only testing the divisors of the constant
+    // term, which is quicker than finding every rational root. A repeated root is only listed once.
+    // Only polynomials with integer coefficients have integer roots found.
+    pub fn integer_roots(&self) -> Vec<i64> {
+        let integer = self
+            .coefficients
+            .iter()
+            .all(|x| *x == x.round() && x.abs() <= MAX_EXACT_INTEGER);
+        if !integer || self.degree() == 0 {
+            return Vec::new();
+        }
+
+        // A zero constant term makes 0 a root, and the rest divide the lowest nonzero term
+        let lowest = self.coefficients.iter().position(|x| *x != 0_f64).unwrap();
+        let mut candidates = Vec::new();
+        if lowest > 0 {
+            candidates.push(0);
+        }
+        if lowest < self.degree() {
+            for divisor in divisors(self.coefficients[lowest] as i64) {
+                candidates.push(divisor);
+                candidates.push(-divisor);
+            }
+        }
+        let mut roots: Vec<i64> = candidates
+            .into_iter()
+            .filter(|candidate| self.is_root(*candidate as f64))
+            .collect();
+        roots.sort_unstable();
+        roots
+    }
+
+    // Checks if x is a root, which is only zero to within the rounding of the polynomial's terms
+    fn is_root(&self, x: f64) -> bool {
+        let scale = self
+            .coefficients
+            .iter()
+            .rev()
+            .fold(0_f64, |acc, coefficient| acc * x.abs() + coefficient.abs());
+        self.evaluate(x).abs() <= TOLERANCE * scale
+    }
+}
+
+// Gets the greatest common divisor of two integers, which is never negative
+pub(super) fn gcd(lhs: i64, rhs: i64) -> i64 {
+    let (mut lhs, mut rhs) = (lhs.abs(), rhs.abs());
+    while rhs != 0 {
+        let remainder = lhs % rhs;
+        lhs = rhs;
+        rhs = remainder;
+    }
+    lhs
+}
+
+// Gets the positive divisors of a nonzero integer, in ascending order
+fn divisors(value: i64) -> Vec<i64> {
+    let value = value.abs();
+    let mut small = Vec::new();
+    let mut large = Vec::new();
+    let mut divisor = 1;
+    while divisor * divisor <= value {
+        if value % divisor == 0 {
+            small.push(divisor);
+            if divisor * divisor != value {
+                large.push(value / divisor);
+            }
+        }
+        divisor += 1;
+    }
+    small.extend(large.into_iter().rev());
+    small
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Tests finding rational roots with the rational root theorem
+    #[test]
+    fn rational_roots() {
+        let poly = Polynomial::from("2x^2 - 3x + 1");
+        assert_eq!(
+            poly.rational_root_candidates(),
+            vec![
+                Rational::new(1, 1),
+                Rational::new(-1, 1),
+                Rational::new(1, 2),
+                Rational::new(-1, 2)
+            ]
+        );
+        assert_eq!(
+            poly.rational_roots(),
+            vec![Rational::new(1, 1), Rational::new(1, 2)]
+        );
+        assert_eq!(
+            Polynomial::from("6x^3 - 5x^2 + x").rational_roots(),
+            vec![
+                Rational::new(0, 1),
+                Rational::new(1, 2),
+                Rational::new(1, 3)
+            ]
+        );
+        assert!(Polynomial::from("x^2 - 2").rational_roots().is_empty());
+        assert!(Polynomial::from("x^2 - 0.5")
+            .rational_root_candidates()
+            .is_empty());
+        assert_eq!(Rational::new(2, -4).to_string(), "-1/2");
+    }
+
+    // Tests that a fraction can't have a zero denominator
+    #[test]
+    #[should_panic(expected = "the denominator of a fraction can't be zero")]
+    fn zero_denominator() {
+        Rational::new(1, 0);
+    }
+
+    // Tests that coefficients with many divisors don't make the search take too long
+    #[test]
+    fn bounded_rational_roots() {
+        let poly = Polynomial::from("963761198400x^2 - 963761198400");
+        assert_eq!(poly.rational_root_candidates().len(), MAX_ROOT_CANDIDATES);
+        assert!(poly.rational_roots().contains(&Rational::new(1, 1)));
+        assert!(Polynomial::from("963761198400x^2 + x + 963761198400")
+            .rational_roots()
+            .is_empty());
+    }
+
+    // Tests finding integer roots from the divisors of the constant term
+    #[test]
+    fn integer_roots() {
+        assert_eq!(Polynomial::from("x^2 - 5x + 6").integer_roots(), vec![2, 3]);
+        assert_eq!(Polynomial::from("x^2 + 4x + 4").integer_roots(), vec![-2]);
+        assert_eq!(Polynomial::from("2x^2 - 3x + 1").integer_roots(), vec![1]);
+        assert_eq!(Polynomial::from("x^3 - 4x").integer_roots(), vec![-2, 0, 2]);
+        assert!(Polynomial::from("x^2 - 2").integer_roots().is_empty());
+        assert!(Polynomial::from("x^2 - 0.25").integer_roots().is_empty());
+        assert!(Polynomial::from("7").integer_roots().is_empty());
+    }
+}
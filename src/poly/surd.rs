@@ -0,0 +1,112 @@
+use super::rational::{gcd, MAX_EXACT_INTEGER};
+use super::Polynomial;
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::math::F64Ext;
+use alloc::format;
+use alloc::string::String;
+
+impl Polynomial {
+    // Gets the roots of a quadratic with integer coefficients as the exact expression
+    // (-B ± k√m)/2A, with the square factors of the discriminant pulled out of the radical and the
+    // fraction in lowest terms, so x^2 - 8 gives ±2√2. Returns None when the roots are rational
+    // or complex, or the polynomial isn't a quadratic with integer coefficients.
+    pub fn surd_form(&self) -> Option<String> {
+        let integer = self
+            .coefficients
+            .iter()
+            .all(|x| *x == x.round() && x.abs() <= MAX_EXACT_INTEGER);
+        if self.degree() != 2 || !integer {
+            return None;
+        }
+        let (a, b, c) = (self.a() as i64, self.b() as i64, self.c() as i64);
+        let discriminant = b
+            .checked_mul(b)?
+            .checked_sub(a.checked_mul(c)?.checked_mul(4)?)?;
+        if discriminant <= 0 {
+            return None;
+        }
+        let (multiplier, radicand) = simplify_radical(discriminant);
+        if radicand == 1 {
+            return None;
+        }
+
+        // Reduce the fraction, keeping its denominator positive
+        let (mut constant, mut denominator) = (-b, 2 * a);
+        let divisor = gcd(gcd(constant, multiplier), denominator);
+        let multiplier = multiplier / divisor;
+        constant /= divisor;
+        denominator /= divisor;
+        if denominator < 0 {
+            constant = -constant;
+            denominator = -denominator;
+        }
+
+        let surd = if multiplier == 1 {
+            format!("√{}", radicand)
+        } else {
+            format!("{}√{}", multiplier, radicand)
+        };
+        Some(match (constant, denominator) {
+            (0, 1) => format!("±{}", surd),
+            (0, _) => format!("±{}/{}", surd, denominator),
+            (_, 1) => format!("{} ± {}", constant, surd),
+            _ => format!("({} ± {})/{}", constant, surd, denominator),
+        })
+    }
+}
+
+// The largest factor checked when pulling square factors out of a radical, which keeps a large
+// radicand with no small factors from taking billions of steps
+const MAX_RADICAL_FACTOR: i64 = 1_000_000;
+
+// Splits a positive integer n into k and m where n = k^2 * m, so √n is k√m. m has no square
+// factors up to MAX_RADICAL_FACTOR^2, so a huge radicand may be left partly unsimplified.
+fn simplify_radical(value: i64) -> (i64, i64) {
+    let (mut multiplier, mut radicand) = (1, value);
+    let mut factor = 2;
+    // Dividing rather than squaring the factor keeps the comparison from overflowing
+    while factor <= radicand / factor && factor <= MAX_RADICAL_FACTOR {
+        while radicand % (factor * factor) == 0 {
+            radicand /= factor * factor;
+            multiplier *= factor;
+        }
+        factor += 1;
+    }
+    (multiplier, radicand)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Tests writing irrational roots as simplified surds
+    #[test]
+    fn surd_form() {
+        assert_eq!(Polynomial::from("x^2 - 2").surd_form(), Some("±√2".into()));
+        assert_eq!(Polynomial::from("x^2 - 8").surd_form(), Some("±2√2".into()));
+        assert_eq!(
+            Polynomial::from("x^2 + 2x - 1").surd_form(),
+            Some("-1 ± √2".into())
+        );
+        assert_eq!(
+            Polynomial::from("2x^2 - 2x - 1").surd_form(),
+            Some("(1 ± √3)/2".into())
+        );
+        assert_eq!(
+            Polynomial::from("-4x^2 + 3").surd_form(),
+            Some("±√3/2".into())
+        );
+        assert_eq!(Polynomial::from("x^2 - 4").surd_form(), None);
+        assert_eq!(Polynomial::from("x^2 + 2").surd_form(), None);
+        assert_eq!(Polynomial::from("x^2 - 0.5").surd_form(), None);
+        assert_eq!(simplify_radical(72), (6, 2));
+        assert_eq!(simplify_radical(7), (1, 7));
+
+        // A large prime radicand gives up on the search instead of trying every factor
+        assert_eq!(
+            Polynomial::from("x^2 - 1000000000000037").surd_form(),
+            Some("±√1000000000000037".into())
+        );
+        assert_eq!(simplify_radical(i64::MAX), (7, i64::MAX / 49));
+    }
+}
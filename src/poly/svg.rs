@@ -0,0 +1,147 @@
+use super::Polynomial;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+// The size of the plot in pixels
+const WIDTH: f64 = 400_f64;
+const HEIGHT: f64 = 300_f64;
+
+// How many points along the curve are plotted
+const SAMPLES: usize = 200;
+
+impl Polynomial {
+    // Plots the polynomial as an SVG image, with the axes and a marker on each real root. The
+    // plot is ranged around the roots and the vertex, so they're all in view.
+    pub fn to_svg(&self) -> String {
+        let roots: Vec<f64> = self.real_roots();
+        let (x_min, x_max) = self.plot_range(&roots);
+
+        // Sample the curve, and range the y axis to fit it along with the x axis
+        let points: Vec<(f64, f64)> = (0..=SAMPLES)
+            .map(|i| {
+                let x = x_min + (x_max - x_min) * i as f64 / SAMPLES as f64;
+                (x, self.evaluate(x))
+            })
+            .collect();
+        let (mut y_min, mut y_max) = points
+            .iter()
+            .fold((0_f64, 0_f64), |(lo, hi), &(_, y)| (lo.min(y), hi.max(y)));
+        if y_max - y_min == 0_f64 {
+            y_min -= 1_f64;
+            y_max += 1_f64;
+        }
+        let padding = (y_max - y_min) * 0.1_f64;
+        let (y_min, y_max) = (y_min - padding, y_max + padding);
+
+        // Converts a point on the plot to pixels, where the y axis points down
+        let to_pixels = |x: f64, y: f64| {
+            (
+                (x - x_min) / (x_max - x_min) * WIDTH,
+                HEIGHT - (y - y_min) / (y_max - y_min) * HEIGHT,
+            )
+        };
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {0} {1}\">\n",
+            WIDTH, HEIGHT
+        );
+        let (_, axis_y) = to_pixels(0_f64, 0_f64);
+        let _ = writeln!(
+            svg,
+            "<line class=\"axis\" x1=\"0\" y1=\"{0:.2}\" x2=\"{1}\" y2=\"{0:.2}\" stroke=\"black\"/>",
+            axis_y, WIDTH
+        );
+        if x_min <= 0_f64 && 0_f64 <= x_max {
+            let (axis_x, _) = to_pixels(0_f64, 0_f64);
+            let _ = writeln!(
+                svg,
+                "<line class=\"axis\" x1=\"{0:.2}\" y1=\"0\" x2=\"{0:.2}\" y2=\"{1}\" stroke=\"black\"/>",
+                axis_x, HEIGHT
+            );
+        }
+        let curve: Vec<String> = points
+            .iter()
+            .map(|&(x, y)| {
+                let (x, y) = to_pixels(x, y);
+                format!("{:.2},{:.2}", x, y)
+            })
+            .collect();
+        let _ = writeln!(
+            svg,
+            "<polyline class=\"curve\" fill=\"none\" stroke=\"blue\" points=\"{}\"/>",
+            curve.join(" ")
+        );
+        for root in roots {
+            let (x, y) = to_pixels(root, 0_f64);
+            let _ = writeln!(
+                svg,
+                "<circle class=\"root\" cx=\"{:.2}\" cy=\"{:.2}\" r=\"4\" fill=\"red\"/>",
+                x, y
+            );
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    // Gets the range of x to plot, centered on the roots and the vertex with a margin either side
+    fn plot_range(&self, roots: &[f64]) -> (f64, f64) {
+        let mut points: Vec<f64> = roots.to_vec();
+        if let Some((x, _)) = self.vertex() {
+            points.push(x);
+        }
+        if points.is_empty() {
+            points.push(0_f64);
+        }
+        let lo = points.iter().cloned().fold(f64::INFINITY, f64::min);
+        let hi = points.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let margin = ((hi - lo) * 0.25_f64).max(1_f64);
+        (lo - margin, hi + margin)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Tests that a plot is well formed SVG with a marker on each root
+    #[test]
+    fn svg_plot() {
+        let svg = Polynomial::from("x^2 - 1").to_svg();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<svg").count(), 1);
+        assert_eq!(svg.matches('<').count(), svg.matches('>').count());
+        assert_eq!(svg.matches("/>").count(), svg.lines().count() - 2);
+        assert_eq!(svg.matches("class=\"axis\"").count(), 2);
+        assert_eq!(svg.matches("class=\"curve\"").count(), 1);
+
+        // The markers for the roots at -1 and 1 sit on the x axis
+        let markers: Vec<&str> = svg
+            .lines()
+            .filter(|x| x.contains("class=\"root\""))
+            .collect();
+        assert_eq!(markers.len(), 2);
+        let axis = svg.lines().find(|x| x.contains("class=\"axis\"")).unwrap();
+        let axis_y = axis
+            .split("y1=\"")
+            .nth(1)
+            .unwrap()
+            .split('"')
+            .next()
+            .unwrap();
+        for marker in markers {
+            assert!(marker.contains(&format!("cy=\"{}\"", axis_y)));
+        }
+
+        assert_eq!(
+            Polynomial::from("x^2 + 1")
+                .to_svg()
+                .matches("class=\"root\"")
+                .count(),
+            0
+        );
+        assert!(Polynomial::from("3").to_svg().ends_with("</svg>\n"));
+    }
+}
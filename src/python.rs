@@ -0,0 +1,63 @@
+// Python bindings, built as an extension module with e.g.
+// `cargo rustc --release --lib --features python,pyo3/extension-module --crate-type cdylib`
+use crate::solve::solve as solve_polynomial;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+// Solves a polynomial string, returning a dict with its canonical form, roots and warnings
+#[pyfunction]
+fn solve<'py>(py: Python<'py>, polynomial: &str) -> PyResult<Bound<'py, PyDict>> {
+    let solution =
+        solve_polynomial(polynomial).map_err(|error| PyValueError::new_err(error.to_string()))?;
+    let dict = PyDict::new(py);
+    dict.set_item("polynomial", solution.polynomial.to_string())?;
+    dict.set_item("roots", solution.roots)?;
+    dict.set_item("warnings", solution.warnings)?;
+    Ok(dict)
+}
+
+// Gets the roots of a polynomial string
+#[pyfunction]
+fn roots(polynomial: &str) -> PyResult<(f64, f64)> {
+    solve_polynomial(polynomial)
+        .map(|solution| solution.roots)
+        .map_err(|error| PyValueError::new_err(error.to_string()))
+}
+
+#[pymodule]
+fn factoring(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(solve, module)?)?;
+    module.add_function(wrap_pyfunction!(roots, module)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Tests calling the module's functions through Python
+    #[test]
+    fn python_module() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let module = PyModule::new(py, "factoring").unwrap();
+            factoring(&module).unwrap();
+
+            let roots: (f64, f64) = module
+                .getattr("roots")
+                .unwrap()
+                .call1(("x^2-1",))
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(roots, (1_f64, -1_f64));
+
+            let solution = module.getattr("solve").unwrap().call1(("x^2-1",)).unwrap();
+            let polynomial: String = solution.get_item("polynomial").unwrap().extract().unwrap();
+            assert_eq!(polynomial, "x^2 - 1");
+
+            assert!(module.getattr("roots").unwrap().call1(("x^2.5",)).is_err());
+        });
+    }
+}
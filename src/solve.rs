@@ -0,0 +1,246 @@
+use crate::poly::{
+    term_contributions, ParseError, ParseOptions, Polynomial, RootKind, DISPLAY_THRESHOLD,
+};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+// The version of the JSON schema from `Solution::to_json`. This is bumped whenever a field is
+// removed or changes meaning, so consumers can tell the formats apart.
+pub const SCHEMA_VERSION: u32 = 1;
+
+// The result of solving a polynomial string
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution {
+    // The parsed polynomial
+    pub polynomial: Polynomial,
+    // The roots from the quadratic equation, which are NaN when complex
+    pub roots: (f64, f64),
+    // What kind of roots the polynomial has, which tells apart the NaN roots of a polynomial that
+    // is never zero from one that is always zero
+    pub kind: RootKind,
+    // Problems that didn't stop the polynomial being solved, but may mean the roots aren't what
+    // was meant, like terms that cancelled out or were dropped
+    pub warnings: Vec<String>,
+}
+
+impl Solution {
+    // Finds the roots of a polynomial
+    pub fn new(polynomial: Polynomial) -> Self {
+        let roots = polynomial.roots();
+        let kind = polynomial.root_kind();
+        let mut warnings = Vec::new();
+        if polynomial.effective_degree() > 2 {
+            warnings.push(format!(
+                "dropped the terms above degree 2 from the degree {} polynomial",
+                polynomial.effective_degree()
+            ));
+        }
+        Solution {
+            polynomial,
+            roots,
+            kind,
+            warnings,
+        }
+    }
+
+    // Serializes the solution as JSON, along with the schema version. Complex roots, which are NaN,
+    // are written as null.
+    pub fn to_json(&self) -> String {
+        let coefficients: Vec<String> = self
+            .polynomial
+            .coefficients()
+            .iter()
+            .map(|x| json_number(*x))
+            .collect();
+        let kind = match self.kind {
+            RootKind::Real => "real",
+            RootKind::Complex => "complex",
+            RootKind::NoRoots => "no_roots",
+            RootKind::Infinite => "infinite",
+        };
+        format!(
+            "{{\"schema_version\":{},\"polynomial\":\"{}\",\"coefficients\":[{}],\"roots\":[{},{}],\"kind\":\"{}\"}}",
+            SCHEMA_VERSION,
+            self.polynomial,
+            coefficients.join(","),
+            json_number(self.roots.0),
+            json_number(self.roots.1),
+            kind
+        )
+    }
+}
+
+// Formats a number for JSON, which has no NaN or infinity, so those are null
+fn json_number(value: f64) -> String {
+    if value.is_finite() {
+        format!("{}", value + 0_f64)
+    } else {
+        "null".into()
+    }
+}
+
+// Parses a polynomial string and finds its roots
+pub fn solve(input: &str) -> Result<Solution, ParseError> {
+    solve_with(input, &ParseOptions::default())
+}
+
+// Parses a polynomial string with the given options and finds its roots
+pub fn solve_with(input: &str, options: &ParseOptions) -> Result<Solution, ParseError> {
+    let mut solution = Solution::new(Polynomial::parse_with(input, options)?);
+    solution
+        .warnings
+        .splice(0..0, parse_warnings(input, options));
+    Ok(solution)
+}
+
+// Finds the problems with how a polynomial string was parsed that aren't errors: degrees whose
+// terms cancelled out, either completely or leaving a coefficient too small to trust
+pub fn parse_warnings(input: &str, options: &ParseOptions) -> Vec<String> {
+    let contributions = match term_contributions(input, options) {
+        Ok(contributions) => contributions,
+        Err(_) => return Vec::new(),
+    };
+    let mut warnings = Vec::new();
+    for contribution in contributions.iter().filter(|x| x.terms.len() > 1) {
+        if contribution.total == 0_f64 {
+            warnings.push(format!(
+                "the terms of degree {} cancelled out",
+                contribution.degree
+            ));
+        } else if contribution.total.abs() < DISPLAY_THRESHOLD {
+            warnings.push(format!(
+                "the coefficient of degree {} is almost zero, since its terms almost cancelled out",
+                contribution.degree
+            ));
+        }
+    }
+    warnings
+}
+
+// Solves quadratics read from a stream of packed little-endian [f64; 3] coefficients, highest
+// degree first, writing each pair of roots as packed little-endian [f64; 2]. This skips text
+// parsing for large datasets. Returns how many quadratics were solved, or an error if a record is
+// cut short.
+#[cfg(feature = "std")]
+pub fn solve_binary_stream<R: std::io::Read, W: std::io::Write>(
+    mut input: R,
+    mut output: W,
+) -> std::io::Result<usize> {
+    let mut solved = 0;
+    let mut record = [0_u8; 24];
+    loop {
+        // Fill the record, which may take several reads
+        let mut filled = 0;
+        while filled < record.len() {
+            match input.read(&mut record[filled..]) {
+                Ok(0) => break,
+                Ok(read) => filled += read,
+                Err(error) if error.kind() == std::io::ErrorKind::Interrupted => (),
+                Err(error) => return Err(error),
+            }
+        }
+        if filled == 0 {
+            return Ok(solved);
+        }
+        if filled < record.len() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                format!("record {} is truncated to {} bytes", solved, filled),
+            ));
+        }
+
+        let mut coefficients = [0_f64; 3];
+        for (coefficient, bytes) in coefficients.iter_mut().zip(record.chunks_exact(8)) {
+            let mut array = [0_u8; 8];
+            array.copy_from_slice(bytes);
+            *coefficient = f64::from_le_bytes(array);
+        }
+        let (root1, root2) = Polynomial::from_coefficients(&coefficients).roots();
+        output.write_all(&root1.to_le_bytes())?;
+        output.write_all(&root2.to_le_bytes())?;
+        solved += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Tests solving a polynomial string
+    #[test]
+    fn solve_polynomial() {
+        let solution = solve("x^2 - 1").unwrap();
+        assert_eq!(solution.polynomial, Polynomial::from("x^2 - 1"));
+        assert_eq!(solution.roots, (1_f64, -1_f64));
+        assert_eq!(solution.kind, RootKind::Real);
+        assert!(solve("x^2.5").is_err());
+        assert_eq!(solve("x - x").unwrap().kind, RootKind::Infinite);
+    }
+
+    // Tests warning about terms that cancelled out or were dropped, without failing to solve
+    #[test]
+    fn solution_warnings() {
+        assert!(solve("x^2 - 1").unwrap().warnings.is_empty());
+        assert_eq!(
+            solve("x^2 + 3x - 3x - 4").unwrap().warnings,
+            vec!["the terms of degree 1 cancelled out"]
+        );
+        assert_eq!(
+            solve("x^2 + 0.1x + 0.2x - 0.3x").unwrap().warnings,
+            vec![
+                "the coefficient of degree 1 is almost zero, since its terms almost cancelled out"
+            ]
+        );
+        assert_eq!(
+            solve("x^3 + x^2 = x^3").unwrap().warnings,
+            vec!["the terms of degree 3 cancelled out"]
+        );
+        assert_eq!(
+            solve("x^3 - 1").unwrap().warnings,
+            vec!["dropped the terms above degree 2 from the degree 3 polynomial"]
+        );
+    }
+
+    // Tests serializing a solution as JSON with its schema version
+    #[test]
+    fn solution_json() {
+        assert_eq!(
+            solve("x^2 - 1").unwrap().to_json(),
+            "{\"schema_version\":1,\"polynomial\":\"x^2 - 1\",\"coefficients\":[1,0,-1],\
+             \"roots\":[1,-1],\"kind\":\"real\"}"
+        );
+        let json = solve("x^2 + 1").unwrap().to_json();
+        assert!(json.contains(&format!("\"schema_version\":{}", SCHEMA_VERSION)));
+        assert!(json.contains("\"roots\":[null,null]"));
+        assert!(json.contains("\"kind\":\"complex\""));
+    }
+
+    // Tests solving quadratics from packed binary coefficients
+    #[cfg(feature = "std")]
+    #[test]
+    fn binary_stream() {
+        let mut input = Vec::new();
+        for coefficient in &[
+            1_f64, 0_f64, -1_f64, 1_f64, 4_f64, 4_f64, 1_f64, 0_f64, 1_f64,
+        ] {
+            input.extend_from_slice(&coefficient.to_le_bytes());
+        }
+        let mut output = Vec::new();
+        assert_eq!(solve_binary_stream(&input[..], &mut output).unwrap(), 3);
+        let roots: Vec<f64> = output
+            .chunks_exact(8)
+            .map(|bytes| {
+                let mut array = [0_u8; 8];
+                array.copy_from_slice(bytes);
+                f64::from_le_bytes(array)
+            })
+            .collect();
+        assert_eq!(&roots[..4], &[1_f64, -1_f64, -2_f64, -2_f64]);
+        assert!(roots[4].is_nan() && roots[5].is_nan());
+
+        let error = solve_binary_stream(&input[..30], &mut Vec::new()).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+        assert_eq!(solve_binary_stream(&[][..], &mut Vec::new()).unwrap(), 0);
+    }
+}
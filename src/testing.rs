@@ -0,0 +1,90 @@
+use crate::poly::{sort_roots, Polynomial};
+use alloc::format;
+use alloc::string::String;
+
+// The tolerance `assert_roots!` allows by default, relative to the size of each expected root
+pub const DEFAULT_TOLERANCE: f64 = 1e-9;
+
+// Asserts that a polynomial string has exactly the given real roots, in any order, to within a
+// tolerance relative to each root (or absolute for roots smaller than 1). A repeated root must be
+// listed once for each time it repeats.
+//
+//     factoring::assert_roots!("x^2 - 1", [-1.0, 1.0]);
+//     factoring::assert_roots!("x^2 - 3", [1.73205, -1.73205], 1e-5);
+#[macro_export]
+macro_rules! assert_roots {
+    ($input:expr, [$($root:expr),* $(,)?]) => {
+        $crate::assert_roots!($input, [$($root),*], $crate::testing::DEFAULT_TOLERANCE)
+    };
+    ($input:expr, [$($root:expr),* $(,)?], $tolerance:expr) => {
+        if let Err(message) = $crate::testing::check_roots($input, &[$($root),*], $tolerance) {
+            panic!("{}", message);
+        }
+    };
+}
+
+// Checks a polynomial string's real roots against the expected ones for `assert_roots!`, giving
+// the failure message if they don't match
+pub fn check_roots(input: &str, expected: &[f64], tolerance: f64) -> Result<(), String> {
+    let polynomial: Polynomial = input
+        .parse()
+        .map_err(|error| format!("assertion failed: couldn't parse `{}`: {}", input, error))?;
+    let mut roots = polynomial.real_roots();
+    let mut expected = expected.to_vec();
+    sort_roots(&mut roots);
+    sort_roots(&mut expected);
+    let matches = roots.len() == expected.len()
+        && roots.iter().zip(expected.iter()).all(|(root, expected)| {
+            (root - expected).abs() <= tolerance * expected.abs().max(1_f64)
+        });
+    if matches {
+        Ok(())
+    } else {
+        Err(format!(
+            "assertion failed: roots of `{}` don't match\n    found: {:?}\n expected: {:?}",
+            input, roots, expected
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Tests asserting the roots of polynomials, in any order
+    #[test]
+    fn assert_roots() {
+        crate::assert_roots!("x^2 - 1", [-1.0, 1.0]);
+        crate::assert_roots!("x^2 - 1", [1.0, -1.0]);
+        crate::assert_roots!("x^2 + 4x + 4", [-2.0, -2.0]);
+        crate::assert_roots!("x^2 + 1", []);
+        crate::assert_roots!("x^2 - 3", [1.73205, -1.73205], 1e-5);
+    }
+
+    // Tests the message when the roots don't match
+    #[test]
+    fn assert_roots_failure() {
+        assert_eq!(
+            check_roots("x^2 - 1", &[1_f64, 2_f64], DEFAULT_TOLERANCE),
+            Err(
+                "assertion failed: roots of `x^2 - 1` don't match\n    found: [-1.0, 1.0]\n \
+                 expected: [1.0, 2.0]"
+                    .into()
+            )
+        );
+        assert_eq!(
+            check_roots("x^2.5", &[], DEFAULT_TOLERANCE),
+            Err(
+                "assertion failed: couldn't parse `x^2.5`: Invalid degree in term \"x^2.5\" at \
+                 position 0."
+                    .into()
+            )
+        );
+        let panic = std::panic::catch_unwind(|| crate::assert_roots!("x^2 - 1", [1.0]));
+        assert_eq!(
+            panic.unwrap_err().downcast_ref::<String>().unwrap(),
+            "assertion failed: roots of `x^2 - 1` don't match\n    found: [-1.0, 1.0]\n \
+             expected: [1.0]"
+        );
+    }
+}
@@ -0,0 +1,515 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+// Runs the factoring binary with the given arguments, returning its stdout
+fn factoring(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_factoring"))
+        .args(args)
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+// Tests reading the polynomials from stdin when the argument is -
+#[test]
+fn stdin_argument() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_factoring"))
+        .arg("-")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(b"x^2 - 1\n2x + 1\n")
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Factors of (x^2 - 1) are 1.0000, and -1.0000\nFactor of (2x + 1) is -0.5000\n"
+    );
+}
+
+// Tests that strict mode rejects lossy polynomials and solves the rest as usual
+#[test]
+fn strict_flag() {
+    let output = Command::new(env!("CARGO_BIN_EXE_factoring"))
+        .args(["--strict", "--method", "companion", "x^3 + 1"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert_eq!(
+        factoring(&["--strict", "x^2 + 1"]),
+        "Factors of (x^2 + 1) are imaginary\n"
+    );
+}
+
+// Tests printing only the number of distinct real roots
+#[test]
+fn count_real_roots_flag() {
+    assert_eq!(factoring(&["--count-real-roots", "x^2+1"]), "0\n");
+    assert_eq!(factoring(&["--count-real-roots", "x^2+4x+4"]), "1\n");
+    assert_eq!(factoring(&["--count-real-roots", "x^2-1"]), "2\n");
+}
+
+// Tests factoring -1 out of the canonical form
+#[test]
+fn positive_leading_flag() {
+    assert_eq!(
+        factoring(&["--parse-only", "--positive-leading", "-x^2 + 4x - 4"]),
+        "-(x^2 - 4x + 4)\n"
+    );
+    assert_eq!(
+        factoring(&["--parse-only", "-x^2 + 4x - 4"]),
+        "-x^2 + 4x - 4\n"
+    );
+}
+
+// Tests that timings go to stderr, leaving the result alone on stdout
+#[test]
+fn time_flag() {
+    let output = Command::new(env!("CARGO_BIN_EXE_factoring"))
+        .args(["--time", "x^2 - 1"])
+        .output()
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Factors of (x^2 - 1) are 1.0000, and -1.0000\n"
+    );
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let lines: Vec<&str> = stderr.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("Parsed in ") && lines[0].ends_with("µs"));
+    assert!(lines[1].starts_with("Solved in ") && lines[1].ends_with("µs"));
+}
+
+// Tests solving a polynomial given directly by its coefficients
+#[test]
+fn coeffs_flag() {
+    assert_eq!(
+        factoring(&["--coeffs", "1", "4", "4"]),
+        "Factors of (1 4 4) are -2.0000, and -2.0000\n"
+    );
+    assert_eq!(
+        factoring(&["--coeffs", "1", "0", "-1"]),
+        "Factors of (1 0 -1) are 1.0000, and -1.0000\n"
+    );
+}
+
+// Tests that a polynomial starting with a minus sign isn't mistaken for a flag
+#[test]
+fn leading_minus() {
+    assert_eq!(
+        factoring(&["-x^2 + 4x - 4"]),
+        "Factors of (-x^2 + 4x - 4) are 2.0000, and 2.0000\n"
+    );
+    assert_eq!(
+        factoring(&["--factored", "-x^2 + 1"]),
+        "Factored form of (-x^2 + 1) is -(x + 1)(x - 1)\n"
+    );
+}
+
+// Tests expanding a factored polynomial into its standard form
+#[test]
+fn expand_subcommand() {
+    assert_eq!(factoring(&["expand", "(x+2)(x-3)"]), "x^2 - x - 6\n");
+    assert_eq!(factoring(&["expand", "2(x + 1)(x - 1)"]), "2x^2 - 2\n");
+    assert_eq!(
+        factoring(&["expand", "(x+1)(x+2)(x+3)"]),
+        "x^3 + 6x^2 + 11x + 6\n"
+    );
+}
+
+// Tests finding where two polynomials intersect
+#[test]
+fn intersect_subcommand() {
+    assert_eq!(
+        factoring(&["intersect", "x^2", "2x - 1"]),
+        "(x^2) and (2x - 1) intersect at (1.0000, 1.0000)\n"
+    );
+    assert_eq!(
+        factoring(&["intersect", "x^2", "x + 2"]),
+        "(x^2) and (x + 2) intersect at (-1.0000, 1.0000), and (2.0000, 4.0000)\n"
+    );
+    assert_eq!(
+        factoring(&["intersect", "x^2", "-1"]),
+        "(x^2) and (-1) don't intersect\n"
+    );
+}
+
+// Tests solving each line of a polynomial argument with several lines
+#[test]
+fn multi_line_argument() {
+    assert_eq!(
+        factoring(&["x^2 - 1\n2x + 1\n\n"]),
+        "Factors of (x^2 - 1) are 1.0000, and -1.0000\nFactor of (2x + 1) is -0.5000\n"
+    );
+}
+
+// Tests printing the canonical form of a polynomial without solving it
+#[test]
+fn parse_only() {
+    assert_eq!(factoring(&["--parse-only", "x^2+4x+4"]), "x^2 + 4x + 4\n");
+    assert_eq!(factoring(&["--parse-only", "x^4 = 1"]), "x^4 - 1\n");
+}
+
+// Tests writing the results to a file instead of stdout
+#[test]
+fn output_file() {
+    let path = std::env::temp_dir().join("factoring_output_file_test.txt");
+    let path_str = path.to_str().unwrap();
+    assert_eq!(factoring(&["--output-file", path_str, "x^2 - 1"]), "");
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        "Factors of (x^2 - 1) are 1.0000, and -1.0000\n"
+    );
+    assert_eq!(
+        factoring(&["--output-file", path_str, "--format", "latex", "x^2 - 1"]),
+        ""
+    );
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        "x = \\frac{0 \\pm \\sqrt{4}}{2}\n"
+    );
+    std::fs::remove_file(&path).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_factoring"))
+        .args(["--output-file", "/nonexistent/results.txt", "x^2 - 1"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .starts_with("error: Couldn't open output file /nonexistent/results.txt"));
+}
+
+// Tests the exact output of each output mode against snapshots
+#[test]
+fn output_snapshots() {
+    insta::assert_snapshot!("default", factoring(&["x^2 - 5x + 6"]));
+    insta::assert_snapshot!("imaginary", factoring(&["x^2 + 1"]));
+    insta::assert_snapshot!("linear", factoring(&["2x - 1"]));
+    insta::assert_snapshot!("factored", factoring(&["--factored", "2x^2 - 2"]));
+    insta::assert_snapshot!("latex", factoring(&["--format", "latex", "x^2 + 4x + 4"]));
+    insta::assert_snapshot!("latex_linear", factoring(&["--format", "latex", "2x - 4"]));
+    insta::assert_snapshot!(
+        "template",
+        factoring(&["--template", "{root1} {root2} {disc}", "x^2 - 1"])
+    );
+    insta::assert_snapshot!(
+        "rational_check",
+        factoring(&["--rational-check", "x^2 - 2"])
+    );
+    insta::assert_snapshot!(
+        "cubic",
+        factoring(&["--method", "cardano", "x^3 - 6x^2 + 11x - 6"])
+    );
+}
+
+// Tests that warnings about how the polynomial was parsed go to stderr without stopping it being solved
+#[test]
+fn parse_warnings() {
+    let output = Command::new(env!("CARGO_BIN_EXE_factoring"))
+        .arg("x^2 + 3x - 3x - 4")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Factors of (x^2 + 3x - 3x - 4) are 2.0000, and -2.0000\n"
+    );
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "warning: the terms of degree 1 cancelled out\n"
+    );
+}
+
+// Tests that a semicolon or period ending the polynomial is accepted and ignored
+#[test]
+fn trailing_punctuation() {
+    assert_eq!(
+        factoring(&["x^2 - 1;"]),
+        "Factors of (x^2 - 1;) are 1.0000, and -1.0000\n"
+    );
+    assert_eq!(
+        factoring(&["x^2 - 1."]),
+        "Factors of (x^2 - 1.) are 1.0000, and -1.0000\n"
+    );
+    assert_eq!(factoring(&["x^2; - 1"]), "");
+}
+
+// Tests raising the length limit on polynomials with --max-length
+#[test]
+fn max_length_flag() {
+    let polynomial = format!("x^2 - 1{}", " + 0".repeat(36));
+    assert_eq!(polynomial.len(), 151);
+    let output = Command::new(env!("CARGO_BIN_EXE_factoring"))
+        .arg(&polynomial)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "error: Invalid value for '<POLYNOMIAL>': Polynomial too long.\n"
+    );
+    assert_eq!(
+        factoring(&["--max-length", "200", &polynomial]),
+        format!("Factors of ({}) are 1.0000, and -1.0000\n", polynomial)
+    );
+    assert_eq!(factoring(&["--max-length", "100000", "x"]), "");
+}
+
+// Tests printing irrational roots in simplified radical form
+#[test]
+fn surd_flag() {
+    assert_eq!(
+        factoring(&["--surd", "x^2 - 2"]),
+        "Factors of (x^2 - 2) are ±√2\n"
+    );
+    assert_eq!(
+        factoring(&["--surd", "x^2 - 8"]),
+        "Factors of (x^2 - 8) are ±2√2\n"
+    );
+    assert_eq!(
+        factoring(&["--surd", "x^2 - 1"]),
+        "Factors of (x^2 - 1) are 1.0000, and -1.0000\n"
+    );
+}
+
+// Tests giving up on stdin when nothing is written to it in time
+#[test]
+fn stdin_timeout_flag() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_factoring"))
+        .args(["--stdin-timeout", "100", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(1000));
+    let _ = stdin.write_all(b"x^2 - 1\n");
+    drop(stdin);
+    let output = child.wait_with_output().unwrap();
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "error: Timed out after 100ms waiting for stdin.\n"
+    );
+
+    // Input written in time is solved as usual
+    let mut child = Command::new(env!("CARGO_BIN_EXE_factoring"))
+        .args(["--stdin-timeout", "5000", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    stdin.write_all(b"x^2 - 1\n").unwrap();
+    drop(stdin);
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Factors of (x^2 - 1) are 1.0000, and -1.0000\n"
+    );
+
+    // Input already written and closed before it's read is solved as usual
+    let mut child = Command::new(env!("CARGO_BIN_EXE_factoring"))
+        .args(["--stdin-timeout", "1000", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    stdin.write_all(b"x^2 - 1\n").unwrap();
+    drop(stdin);
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Factors of (x^2 - 1) are 1.0000, and -1.0000\n"
+    );
+
+    // A timeout of 0 is rejected, since it couldn't read anything
+    let output = Command::new(env!("CARGO_BIN_EXE_factoring"))
+        .args(["--stdin-timeout", "0", "-"])
+        .stdin(Stdio::null())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("Timeout must be at least 1 millisecond."));
+}
+
+// Tests rounding the coefficients before solving
+#[test]
+fn round_coeffs_flag() {
+    assert_eq!(
+        factoring(&["--round-coeffs", "0", "--parse-only", "1.4x^2 - 2.6x + 0.2"]),
+        "x^2 - 3x\n"
+    );
+    assert_eq!(
+        factoring(&["--round-coeffs", "1", "x^2 - 1.04"]),
+        "Factors of (x^2 - 1.04) are 1.0000, and -1.0000\n"
+    );
+}
+
+// Tests printing where a polynomial is positive
+#[test]
+fn positive_where_flag() {
+    assert_eq!(
+        factoring(&["--positive-where", "x^2 - 1"]),
+        "x < -1 or x > 1\n"
+    );
+    assert_eq!(factoring(&["--positive-where", "x^2 + 1"]), "all x\n");
+}
+
+// Tests that complex roots are only called imaginary when their real part is 0
+#[test]
+fn complex_wording() {
+    assert_eq!(
+        factoring(&["x^2 + 1"]),
+        "Factors of (x^2 + 1) are imaginary\n"
+    );
+    assert_eq!(
+        factoring(&["x^2 + x + 1"]),
+        "Factors of (x^2 + x + 1) are complex\n"
+    );
+}
+
+// Tests solving a polynomial with arithmetic in its coefficients
+#[test]
+fn arithmetic_coefficients() {
+    assert_eq!(
+        factoring(&["--parse-only", "(3+2)x^2 - (10/2)"]),
+        "5x^2 - 5\n"
+    );
+}
+
+// Tests printing rational roots as fractions alongside their decimals
+#[test]
+fn fractions_flag() {
+    assert_eq!(
+        factoring(&["--fractions", "2x^2 - 3x + 1"]),
+        "Factors of (2x^2 - 3x + 1) are 1.0000 (1), and 0.5000 (1/2)\n"
+    );
+    assert_eq!(
+        factoring(&["--fractions", "x^2 - 2"]),
+        "Factors of (x^2 - 2) are 1.4142, and -1.4142\n"
+    );
+
+    // Coefficients with thousands of divisors are still solved quickly
+    assert_eq!(
+        factoring(&["--fractions", "963761198400x^2 + x + 963761198400"]),
+        "Factors of (963761198400x^2 + x + 963761198400) are complex\n"
+    );
+    assert_eq!(
+        factoring(&["--fractions", "963761198400x^2 - 963761198400"]),
+        "Factors of (963761198400x^2 - 963761198400) are 1.0000 (1), and -1.0000 (-1)\n"
+    );
+}
+
+// Tests that exact mode falls back to the usual output for linear, constant and zero polynomials
+#[cfg(feature = "exact")]
+#[test]
+fn exact_flag_non_quadratic() {
+    assert_eq!(
+        factoring(&["--exact", "x^2 - 5x + 6"]),
+        "Factors of (x^2 - 5x + 6) are 3, and 2\n"
+    );
+    assert_eq!(factoring(&["--exact", "2x - 4"]), factoring(&["2x - 4"]));
+    assert_eq!(factoring(&["--exact", "5"]), factoring(&["5"]));
+    assert_eq!(factoring(&["--exact", "x - x"]), factoring(&["x - x"]));
+}
+
+// Tests that exact mode evaluates arithmetic in coefficients exactly
+#[cfg(feature = "exact")]
+#[test]
+fn exact_flag_arithmetic_coefficients() {
+    assert_eq!(
+        factoring(&["--exact", "(3+2)x^2 - 5"]),
+        "Factors of ((3+2)x^2 - 5) are 1, and -1\n"
+    );
+}
+
+// Tests that exact mode reads the polynomial with the same options, and skips coefficients that
+// were given as floats or rounded
+#[cfg(feature = "exact")]
+#[test]
+fn exact_flag_options() {
+    assert_eq!(
+        factoring(&["--exact", "--decimal-comma", "x^2 - 2,25"]),
+        "Factors of (x^2 - 2,25) are 3/2, and -3/2\n"
+    );
+    assert_eq!(
+        factoring(&["--exact", "--round-coeffs", "0", "x^2 - 2.25"]),
+        factoring(&["--round-coeffs", "0", "x^2 - 2.25"])
+    );
+    assert_eq!(
+        factoring(&["--exact", "--coeffs", "1", "0", "-4"]),
+        factoring(&["--coeffs", "1", "0", "-4"])
+    );
+}
+
+// Tests that exact mode reads implicit exponents when they're enabled
+#[cfg(feature = "exact")]
+#[test]
+fn exact_flag_implicit_exponent() {
+    assert_eq!(
+        factoring(&["--exact", "--implicit-exponent", "4x2 - 1"]),
+        "Factors of (4x2 - 1) are 1/2, and -1/2\n"
+    );
+}
+
+// Tests that exact mode can't be combined with the other ways of writing the roots, which it would
+// otherwise override
+#[cfg(feature = "exact")]
+#[test]
+fn exact_flag_conflicts() {
+    for flag in &["--factored", "--fractions", "--surd"] {
+        let output = Command::new(env!("CARGO_BIN_EXE_factoring"))
+            .args(["--exact", flag, "x^2 - 1"])
+            .output()
+            .unwrap();
+        assert!(!output.status.success());
+        assert!(output.stdout.is_empty());
+    }
+}
+
+// Tests that a cubic whose x^3 terms cancel out is solved as the quadratic it really is
+#[test]
+fn cancelled_leading_term() {
+    assert_eq!(
+        factoring(&["0.1x^3 + 0.2x^3 - 0.3x^3 + x^2 - 1"]),
+        "Factors of (0.1x^3 + 0.2x^3 - 0.3x^3 + x^2 - 1) are 1.0000, and -1.0000\n"
+    );
+}
+
+// Tests that a mistyped flag is reported rather than read as the polynomial
+#[test]
+fn mistyped_flag() {
+    for args in [&["--factord"][..], &["--factord", "x^2 - 1"]] {
+        let output = Command::new(env!("CARGO_BIN_EXE_factoring"))
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(!output.status.success());
+        assert!(output.stdout.is_empty());
+    }
+    let output = Command::new(env!("CARGO_BIN_EXE_factoring"))
+        .arg("--factord")
+        .output()
+        .unwrap();
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("'--factord' isn't a known flag."));
+    assert_eq!(
+        factoring(&["-x^2 + 1"]),
+        "Factors of (-x^2 + 1) are -1.0000, and 1.0000\n"
+    );
+}
@@ -0,0 +1,14 @@
+// Builds the solver without std, checking it still works when backed by libm
+#![cfg(not(feature = "std"))]
+
+use factoring::poly::Polynomial;
+
+#[test]
+fn no_std_solver() {
+    let poly: Polynomial = "x^2 - 5x + 6".parse().unwrap();
+
+    assert_eq!(poly.discriminant(), 1_f64);
+    assert_eq!(poly.roots(), (3_f64, 2_f64));
+    assert_eq!(poly.evaluate(3_f64), 0_f64);
+    assert_eq!(poly.evaluate(0_f64), 6_f64);
+}